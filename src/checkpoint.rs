@@ -0,0 +1,107 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// One list page's worth of discovered URLs.
+#[derive(Serialize, Deserialize)]
+struct CheckpointPage {
+    page: usize,
+    urls: Vec<String>,
+}
+
+/// Append-only log of the URLs found on each list page, one JSON line per
+/// page, so an interrupted discovery can pick up after the last page it
+/// finished instead of starting over. Removed once discovery completes.
+pub struct DiscoveryCheckpoint {
+    path: PathBuf,
+    file: File,
+    pages: Vec<CheckpointPage>,
+}
+
+impl DiscoveryCheckpoint {
+    /// Opens the checkpoint. With `resume` the pages already logged are kept
+    /// and new pages appended, otherwise it starts empty.
+    pub fn open(path: &str, resume: bool) -> Result<Self> {
+        let path = PathBuf::from(path);
+        let pages = if resume { read_pages(&path)? } else { Vec::new() };
+
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let mut options = OpenOptions::new();
+        options.create(true);
+        if resume {
+            options.append(true);
+        } else {
+            options.write(true).truncate(true);
+        }
+        let file = options
+            .open(&path)
+            .with_context(|| format!("Failed to open discovery checkpoint {}", path.display()))?;
+
+        Ok(Self { path, file, pages })
+    }
+
+    /// The last page logged, if any.
+    pub fn last_page(&self) -> Option<usize> {
+        self.pages.iter().map(|page| page.page).max()
+    }
+
+    /// Every URL logged so far, in discovery order without repeats.
+    pub fn urls(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut urls: Vec<String> = Vec::new();
+        for url in self.pages.iter().flat_map(|page| &page.urls) {
+            if seen.insert(url) {
+                urls.push(url.clone());
+            }
+        }
+        urls
+    }
+
+    /// Logs a finished page and flushes it, so it survives a crash right after.
+    pub fn record(&mut self, page: usize, urls: &[String]) -> Result<()> {
+        let line = serde_json::to_string(&CheckpointPage {
+            page,
+            urls: urls.to_vec(),
+        })?;
+        writeln!(self.file, "{}", line)?;
+        self.file.flush()?;
+        Ok(())
+    }
+
+    /// Discovery completed, so there is nothing left to resume.
+    pub fn finish(self) -> Result<()> {
+        drop(self.file);
+        fs::remove_file(&self.path)
+            .with_context(|| format!("Failed to remove discovery checkpoint {}", self.path.display()))
+    }
+}
+
+/// Reads the logged pages; a missing file is an empty checkpoint. A line cut
+/// short by a crash mid-write is skipped, which just re-scrapes that page.
+fn read_pages(path: &Path) -> Result<Vec<CheckpointPage>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to read discovery checkpoint {}", path.display()))
+        }
+    };
+
+    Ok(contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(page) => Some(page),
+            Err(e) => {
+                warn!("Skipping unreadable discovery checkpoint line: {}", e);
+                None
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,130 @@
+use anyhow::{Context, Result};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::models::Fund;
+use crate::output::write_atomically;
+
+/// HTTP validators of a profile page from the last time it was scraped.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    validators: Validators,
+    fund: Fund,
+}
+
+/// Outcome of a conditional request for a profile page.
+pub enum Freshness {
+    /// The server answered 304; the fund scraped last time still holds.
+    Unchanged(Box<Fund>),
+    /// The page changed or was never seen; scrape it and `record` these.
+    Changed(Validators),
+}
+
+/// Per-URL `ETag`/`Last-Modified` validators and the fund scraped with
+/// them, persisted between runs so daily refreshes can skip the browser for
+/// profiles the server reports as unchanged.
+pub struct ConditionalCache {
+    path: PathBuf,
+    entries: BTreeMap<String, Entry>,
+    client: reqwest::Client,
+}
+
+impl ConditionalCache {
+    /// Loads the cache, starting empty if the file doesn't exist yet.
+    pub fn load(path: &str) -> Result<Self> {
+        let entries = match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse conditional cache {}", path))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read conditional cache {}", path)),
+        };
+
+        Ok(Self {
+            path: PathBuf::from(path),
+            entries,
+            client: reqwest::Client::new(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Asks the server whether `url` changed since it was recorded, sending
+    /// `If-None-Match`/`If-Modified-Since` from the stored validators.
+    pub async fn check(&self, url: &str) -> Result<Freshness> {
+        let entry = self.entries.get(url);
+
+        let mut request = self.client.get(url);
+        if let Some(entry) = entry {
+            if let Some(etag) = &entry.validators.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.validators.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = request.send().await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(entry) = entry {
+                return Ok(Freshness::Unchanged(Box::new(entry.fund.clone())));
+            }
+        }
+
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+        Ok(Freshness::Changed(Validators {
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        }))
+    }
+
+    /// Stores the validators a fund was scraped under. Pages served without
+    /// any validator can't be checked later, so they aren't stored.
+    pub fn record(&mut self, url: &str, validators: Validators, fund: &Fund) {
+        if validators.is_empty() {
+            self.entries.remove(url);
+            return;
+        }
+        self.entries.insert(
+            url.to_string(),
+            Entry {
+                validators,
+                fund: fund.clone(),
+            },
+        );
+    }
+
+    pub fn save(&self) -> Result<()> {
+        write_atomically(Path::new(&self.path), |tmp_path| {
+            let mut writer = BufWriter::new(File::create(tmp_path)?);
+            serde_json::to_writer(&mut writer, &self.entries)?;
+            writer.flush()?;
+            Ok(())
+        })
+    }
+}
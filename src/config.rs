@@ -0,0 +1,982 @@
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Parser;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::extractors::{PortfolioMode, DEFAULT_DESC_MAX_LEN, DEFAULT_DESC_MIN_LEN, DEFAULT_PORTFOLIO_VOCABULARY};
+use crate::models::{self, JsonCase, FIELD_NAMES};
+use crate::scraper::LP_LIST_URL;
+
+/// Matches Vestbee LP profile pages such as `https://www.vestbee.com/lp-list/alantra`.
+pub const DEFAULT_PROFILE_URL_PATTERN: &str = r"/lp-list/[^/?#]+/?$";
+
+/// Config file holding named profiles, read from the working directory.
+pub const DEFAULT_CONFIG_FILE: &str = "vestbee-scraper.toml";
+
+/// Text of the list-page buttons that link to a fund profile.
+pub const DEFAULT_DETAILS_LABELS: &[&str] = &["Details"];
+
+/// Texts (case-insensitive) in the list page's title or body that mean the
+/// site is down for maintenance rather than showing funds.
+pub const DEFAULT_MAINTENANCE_MARKERS: &[&str] = &[
+    "under maintenance",
+    "scheduled maintenance",
+    "down for maintenance",
+    "we'll be back soon",
+    "we will be back soon",
+    "temporarily unavailable",
+];
+
+/// Language pages are requested in, so labels and number formats are stable.
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// The command line: a scrape by default, or one of the tools working on
+/// earlier output.
+#[derive(Debug, Parser)]
+#[command(
+    name = "vestbee-scraper",
+    about = "Scrapes the Vestbee LP list into <out-dir>/<basename>.csv and .xlsx.",
+    after_help = "Switches take --flag=false to turn off. Layers apply in the order defaults, \
+--profile, command line, then VESTBEE_* environment variables (VESTBEE_DELAY_MS=500 sets --delay-ms).",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    #[command(flatten)]
+    pub options: Options,
+}
+
+/// Tools working on the output of an earlier run instead of scraping.
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Check an existing CSV and report the problems in it
+    ValidateFile {
+        /// CSV to check
+        path: String,
+        #[command(flatten)]
+        options: Options,
+    },
+    /// Re-clean the fields of an existing CSV into a new one
+    ///
+    /// `--emit-raw <dir>` points at the raw text kept by the run that wrote
+    /// the CSV, so amounts are re-parsed from it.
+    ReprocessFields {
+        /// CSV to read
+        input: String,
+        /// CSV to write
+        output: String,
+        #[command(flatten)]
+        options: Options,
+    },
+    /// Print the JSON Schema of the fund records
+    Schema {
+        /// Write the schema here instead of to stdout
+        path: Option<String>,
+    },
+}
+
+/// The scrape options. Every option is optional here so that each layer
+/// (`--profile`, the command line, `VESTBEE_*` variables) only overrides
+/// what it sets; `Config::default` holds the defaults shown in the help.
+/// Switches take `--flag=false` to turn off.
+#[derive(Debug, Default, Parser)]
+pub struct Options {
+    /// List page to discover funds from, repeatable [default: the LP list]
+    #[arg(long, value_name = "URL", help_heading = "Discovery")]
+    url: Vec<String>,
+    /// Take profile URLs from a sitemap instead of list pages
+    #[arg(long, value_name = "URL", help_heading = "Discovery")]
+    from_sitemap: Option<String>,
+    /// Take profile URLs from a file, one per line, instead of list pages
+    #[arg(long, value_name = "PATH", help_heading = "Discovery")]
+    urls_file: Option<String>,
+    /// Sitemap URLs that are profiles [default: /lp-list/<slug>]
+    #[arg(long, value_name = "REGEX", help_heading = "Discovery")]
+    profile_url_pattern: Option<String>,
+    /// Button text linking to a profile, repeatable [default: Details]
+    #[arg(long, value_name = "TEXT", help_heading = "Discovery")]
+    details_label: Vec<String>,
+    /// Text of the site's maintenance page, repeatable
+    #[arg(long, value_name = "TEXT", help_heading = "Discovery")]
+    maintenance_marker: Vec<String>,
+    /// Continue discovery after the last checkpointed list page
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Discovery")]
+    resume_discovery: Option<bool>,
+    /// Longest wait for the list's first links [default: 30]
+    #[arg(long, value_name = "SECS", help_heading = "Discovery")]
+    list_load_timeout: Option<u64>,
+    /// Only discover funds, list them in <out-dir>/fund_urls.txt and exit
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Discovery")]
+    dry_run: Option<bool>,
+
+    /// Funds scraped at once [default: 4]
+    #[arg(long, value_name = "N", help_heading = "Scraping")]
+    concurrency: Option<usize>,
+    /// Profile pages open at once, from opening to the end of extraction [default: 4]
+    #[arg(long, value_name = "N", help_heading = "Scraping")]
+    max_open_pages: Option<usize>,
+    /// Profiles opened and navigated at once [default: 2]
+    #[arg(long, value_name = "N", help_heading = "Scraping")]
+    max_concurrent_navigations: Option<usize>,
+    /// Longest a profile may take to load before it is retried [default: 30]
+    #[arg(long, value_name = "SECS", help_heading = "Scraping")]
+    nav_timeout_secs: Option<u64>,
+    /// Pause each worker takes between funds [default: 2000]
+    #[arg(long, value_name = "MS", help_heading = "Scraping")]
+    delay_ms: Option<u64>,
+    /// Longest wait for a profile or the next list page [default: 3000]
+    #[arg(long, value_name = "MS", help_heading = "Scraping")]
+    page_wait_ms: Option<u64>,
+    /// Adaptive rate limit in funds per second, replaces --delay-ms
+    #[arg(long, value_name = "RATE", allow_negative_numbers = true, help_heading = "Scraping")]
+    max_rps: Option<f64>,
+    /// Scrape at most n funds, 0 for all
+    #[arg(long, value_name = "N", help_heading = "Scraping")]
+    limit: Option<usize>,
+    /// Scrape in random order
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Scraping")]
+    shuffle: Option<bool>,
+    /// Seed for --shuffle
+    #[arg(long, value_name = "N", help_heading = "Scraping")]
+    seed: Option<u64>,
+    /// Append to the CSV of an interrupted run, skipping its funds
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Scraping")]
+    resume: Option<bool>,
+    /// Stop starting funds after this long and write partial outputs
+    #[arg(long, value_name = "SECS", help_heading = "Scraping")]
+    max_runtime_secs: Option<u64>,
+    /// Stop once the run has retried more than n times
+    #[arg(long, value_name = "N", help_heading = "Scraping")]
+    global_retry_budget: Option<u64>,
+    /// Reuse profiles the server reports unchanged
+    #[arg(long, value_name = "PATH", help_heading = "Scraping")]
+    conditional_cache: Option<String>,
+    /// Show the browser window
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Scraping")]
+    headed: Option<bool>,
+    /// Extra Chromium flag, repeatable
+    // The value is itself a flag, so `--chrome-arg --no-sandbox` must be allowed
+    #[arg(long, value_name = "FLAG", allow_hyphen_values = true, help_heading = "Scraping")]
+    chrome_arg: Vec<String>,
+    /// Chromium binary to launch
+    #[arg(long, value_name = "PATH", help_heading = "Scraping")]
+    chrome_path: Option<String>,
+    /// Browser health check interval [default: 30]
+    #[arg(long, value_name = "SECS", help_heading = "Scraping")]
+    health_check_secs: Option<u64>,
+    /// Language pages are requested in [default: en-US]
+    #[arg(long, value_name = "TAG", help_heading = "Scraping")]
+    locale: Option<String>,
+    /// Sent as the From header
+    #[arg(long, value_name = "EMAIL", help_heading = "Scraping")]
+    contact_email: Option<String>,
+    /// Extra request header, repeatable
+    #[arg(long, value_name = "NAME: VALUE", help_heading = "Scraping")]
+    header: Vec<String>,
+
+    /// Longest description kept, 0 for no cap [default: 1000]
+    #[arg(long, value_name = "CHARS", help_heading = "Extraction")]
+    desc_max_len: Option<usize>,
+    /// Shorter paragraphs aren't used as the description [default: 100]
+    #[arg(long, value_name = "CHARS", help_heading = "Extraction")]
+    desc_min_len: Option<usize>,
+    /// linked or vocabulary [default: linked]
+    #[arg(long, value_name = "MODE", help_heading = "Extraction")]
+    portfolio_mode: Option<PortfolioMode>,
+    /// Word a portfolio company name must contain, repeatable
+    #[arg(long, value_name = "WORD", help_heading = "Extraction")]
+    portfolio_vocabulary: Vec<String>,
+    /// Canonical portfolio company names to map onto
+    #[arg(long, value_name = "PATH", help_heading = "Extraction")]
+    portfolio_roster: Option<String>,
+    /// Write funds without a name for review
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Extraction")]
+    include_empty: Option<bool>,
+    /// Reject funds missing a required field
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Extraction")]
+    strict: Option<bool>,
+    /// Fields --strict requires [default: fund_name,fund_url]
+    #[arg(long, value_name = "COLUMNS", help_heading = "Extraction")]
+    required_fields: Option<String>,
+    /// Fail once more than n funds were rejected
+    #[arg(long, value_name = "N", help_heading = "Extraction")]
+    max_rejected: Option<usize>,
+
+    /// Directory for all outputs, created if missing [default: data]
+    #[arg(long, value_name = "DIR", help_heading = "Output")]
+    out_dir: Option<String>,
+    /// File name of the CSV, Excel and JSONL outputs [default: vestbee_funds]
+    #[arg(long, value_name = "NAME", help_heading = "Output")]
+    basename: Option<String>,
+    /// Comma-separated columns to write [default: all]
+    #[arg(long, value_name = "COLUMNS", help_heading = "Output")]
+    columns: Option<String>,
+    /// Add a confidence column per field
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Output")]
+    confidence_columns: Option<bool>,
+    /// Split the CSV, Excel and JSONL outputs
+    #[arg(long, value_name = "N", help_heading = "Output")]
+    rows_per_file: Option<usize>,
+    /// End the CSV with a totals row
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Output")]
+    csv_summary: Option<bool>,
+    /// Compress the CSV and JSON outputs
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Output")]
+    gzip: Option<bool>,
+    /// Sync the streamed outputs to disk after every fund
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Output")]
+    fsync: Option<bool>,
+    /// Also write all funds as a JSON array
+    #[arg(long, value_name = "PATH", help_heading = "Output")]
+    json: Option<String>,
+    /// snake or camel [default: snake]
+    #[arg(long, value_name = "CASE", help_heading = "Output")]
+    json_case: Option<JsonCase>,
+    /// Also stream funds to <out-dir>/<basename>.jsonl
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Output")]
+    jsonl: Option<bool>,
+    /// Also upsert funds into a SQLite database, keyed on the canonical fund URL
+    #[arg(long, value_name = "PATH", help_heading = "Output")]
+    sqlite: Option<String>,
+    /// Writer tasks per output that accepts parallel writes; the CSV, JSONL and SQLite outputs keep one [default: 1]
+    #[arg(long, value_name = "N", help_heading = "Output")]
+    writer_concurrency: Option<usize>,
+    /// Write each fund's raw extracted text
+    #[arg(long, value_name = "DIR", help_heading = "Output")]
+    emit_raw: Option<String>,
+    /// Write the strategy behind each field
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Output")]
+    provenance: Option<bool>,
+    /// Write the fund/portfolio co-investment graph
+    #[arg(long, value_name = "PATH", help_heading = "Output")]
+    graph: Option<String>,
+    /// Report funds sharing near-identical descriptions
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Output")]
+    duplicate_descriptions: Option<bool>,
+    /// Merge this run's funds into an existing CSV
+    #[arg(long, value_name = "CSV", help_heading = "Output")]
+    merge_into: Option<String>,
+    /// Print the first n funds as a table
+    #[arg(long, value_name = "N", help_heading = "Output")]
+    preview: Option<usize>,
+    /// Upload the outputs (needs the s3 feature)
+    #[arg(long, value_name = "BUCKET", help_heading = "Output")]
+    s3_bucket: Option<String>,
+    /// Key prefix for the uploads
+    #[arg(long, value_name = "PREFIX", help_heading = "Output")]
+    s3_prefix: Option<String>,
+
+    /// Only log warnings and the summary
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Logging and setup")]
+    quiet: Option<bool>,
+    /// Also log the scraper's debug messages, such as the fields each profile lacked
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Logging and setup")]
+    verbose: Option<bool>,
+    /// Also log to this file, rotated daily
+    #[arg(long, value_name = "PATH", help_heading = "Logging and setup")]
+    log_file: Option<String>,
+    /// text or json, for --log-file [default: text]
+    #[arg(long, value_name = "FORMAT", help_heading = "Logging and setup")]
+    log_format: Option<LogFormat>,
+    /// Mask emails and phone numbers in logs
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Logging and setup")]
+    redact_pii: Option<bool>,
+    /// Check that a run could work, then exit
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true", hide_possible_values = true, help_heading = "Logging and setup")]
+    preflight: Option<bool>,
+    /// Apply a named profile from the config file
+    #[arg(long, value_name = "NAME", help_heading = "Logging and setup")]
+    profile: Option<String>,
+    /// Config file holding the profiles [default: vestbee-scraper.toml]
+    #[arg(long, value_name = "PATH", help_heading = "Logging and setup")]
+    config: Option<String>,
+}
+
+impl Options {
+    /// Parses one layer of options, naming `source` in any error.
+    fn parse_layer(args: &[String], source: &str) -> Result<Self> {
+        Self::try_parse_from(program_args(args)).map_err(|e| {
+            let message = e.to_string();
+            let message = message.lines().next().unwrap_or_default();
+            anyhow!("{} (from {})", message.trim_start_matches("error: "), source)
+        })
+    }
+}
+
+/// `args` behind the program name, as clap expects.
+fn program_args(args: &[String]) -> impl Iterator<Item = &str> {
+    std::iter::once("vestbee-scraper").chain(args.iter().map(String::as_str))
+}
+
+/// Prefix for environment variables overriding options, e.g. `VESTBEE_DELAY_MS=500`.
+const ENV_PREFIX: &str = "VESTBEE_";
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub headless: bool,
+    /// List pages to discover funds from, from `--url`; empty means `LP_LIST_URL`.
+    pub url: Vec<String>,
+    pub from_sitemap: Option<String>,
+    /// File of profile URLs, one per line, scraped instead of discovering any.
+    pub urls_file: Option<String>,
+    pub profile_url_pattern: String,
+    pub columns: Vec<&'static str>,
+    pub health_check_secs: u64,
+    /// Profile pages open at once, from opening to the end of extraction.
+    pub max_open_pages: usize,
+    /// Profiles opened and navigated at once. Extraction afterwards doesn't
+    /// touch the site, so this is tighter than `max_open_pages`.
+    pub max_concurrent_navigations: usize,
+    /// Longest wait for the list page to show its first fund links.
+    pub list_load_timeout_secs: u64,
+    /// Longest a profile may take to open and render before the attempt
+    /// counts as timed out.
+    pub nav_timeout_secs: u64,
+    /// Pause each worker takes between funds, 2000 by default.
+    pub delay_ms: u64,
+    /// Longest wait for a profile's name heading, or for the next list page
+    /// after clicking "Next", 3000 by default.
+    pub page_wait_ms: u64,
+    /// Directory every output is written to, created if missing.
+    pub out_dir: String,
+    /// File name, without extension, of the CSV, Excel and JSONL outputs.
+    pub basename: String,
+    /// Funds per CSV, Excel and JSONL file before a new numbered part is
+    /// started; `None` writes one file each.
+    pub rows_per_file: Option<usize>,
+    pub json_path: Option<String>,
+    pub json_case: JsonCase,
+    /// Also stream funds to `<out-dir>/<basename>.jsonl` as they are scraped.
+    pub jsonl: bool,
+    /// SQLite database funds are upserted into as they are scraped.
+    pub sqlite: Option<String>,
+    /// Writer tasks draining the export queue, per exporter that accepts
+    /// parallel writes (see `Exporter::max_writers`).
+    pub writer_concurrency: usize,
+    pub confidence_columns: bool,
+    pub quiet: bool,
+    /// Log this crate's debug messages, on the terminal and in `--log-file`.
+    pub verbose: bool,
+    /// Extra discovery trigger texts; empty means `DEFAULT_DETAILS_LABELS`.
+    pub details_label: Vec<String>,
+    /// Write funds whose name couldn't be found instead of dropping them.
+    pub include_empty: bool,
+    /// File of canonical portfolio company names to map scraped names onto.
+    pub portfolio_roster: Option<String>,
+    /// Path for the fund/portfolio co-investment graph.
+    pub graph_path: Option<String>,
+    /// Directory for per-fund JSON files of the raw, pre-cleaning text.
+    pub emit_raw: Option<String>,
+    /// Gzip the CSV and JSON outputs. Excel files are already compressed.
+    pub gzip: bool,
+    /// Extra flags passed to Chromium, e.g. `--no-sandbox` in containers.
+    pub chrome_arg: Vec<String>,
+    /// Chromium binary to launch instead of the auto-detected one.
+    pub chrome_path: Option<String>,
+    /// BCP 47 language tag sent as `Accept-Language` and used for `Intl`.
+    pub locale: String,
+    /// Sync the CSV to disk after every fund so a power loss can't drop
+    /// rows the OS was still buffering. Each sync waits on the disk, which
+    /// costs milliseconds per fund on SSDs and far more on network storage.
+    pub fsync: bool,
+    /// End the CSV with a totals row (fund count, summed AUM).
+    pub csv_summary: bool,
+    /// Cap for the adaptive rate limiter in funds per second. When set it
+    /// replaces the fixed pause between funds.
+    pub max_rps: Option<f64>,
+    /// Only check that a run could work, then exit.
+    pub preflight: bool,
+    /// Discover fund URLs and write them out without scraping any profile.
+    pub dry_run: bool,
+    /// File of per-profile `ETag`/`Last-Modified` validators; profiles the
+    /// server reports unchanged are reused from it instead of re-scraped.
+    pub conditional_cache: Option<String>,
+    /// Also log to this file, rotated daily (`<name>.YYYY-MM-DD`).
+    pub log_file: Option<String>,
+    pub log_format: LogFormat,
+    /// After the run, report funds sharing near-identical descriptions.
+    pub duplicate_descriptions: bool,
+    /// Continue an interrupted discovery after the last checkpointed page.
+    pub resume_discovery: bool,
+    /// Append to the CSV of an interrupted run, skipping the funds already in it.
+    pub resume: bool,
+    /// Scrape at most this many funds, counted after `--resume` skipped the
+    /// ones already done.
+    pub limit: Option<usize>,
+    /// Existing CSV dataset to merge this run's funds into, logging every
+    /// changed field.
+    pub merge_into: Option<String>,
+    /// Bucket to upload the CSV, Excel and JSON outputs to once written.
+    /// Needs the `s3` feature.
+    pub s3_bucket: Option<String>,
+    /// Key prefix for the uploaded outputs.
+    pub s3_prefix: String,
+    /// Scrape funds in random order rather than discovery order.
+    pub shuffle: bool,
+    /// Seed for `--shuffle`; a random one is picked and logged when unset.
+    pub seed: Option<u64>,
+    /// Longest description kept, in characters; 0 keeps the whole text.
+    pub desc_max_len: usize,
+    /// Paragraphs up to this long are not used for the description.
+    pub desc_min_len: usize,
+    /// Abort once the run as a whole has retried more than this many times.
+    pub global_retry_budget: Option<u64>,
+    /// Funds scraped at once, each worker pausing between its own funds.
+    pub concurrency: usize,
+    /// Reject funds missing any of `required_fields` instead of writing them.
+    pub strict: bool,
+    /// Fields a fund must have in `--strict` mode.
+    pub required_fields: Vec<&'static str>,
+    /// Fail the run once more than this many funds were rejected by `--strict`.
+    pub max_rejected: Option<usize>,
+    /// Sent as the `From` header so the site operator can reach us.
+    pub contact_email: Option<String>,
+    /// Extra request headers sent with every page, from `--header "Name: Value"`.
+    pub headers: Vec<(String, String)>,
+    /// Print the first this many scraped funds as a table on stdout.
+    pub preview: Option<usize>,
+    /// Extra maintenance page texts; empty means `DEFAULT_MAINTENANCE_MARKERS`.
+    pub maintenance_marker: Vec<String>,
+    /// Mask emails and phone numbers in log output.
+    pub redact_pii: bool,
+    /// Stop starting funds once the run has taken this long and finish the
+    /// outputs with what was scraped. Combine with `shuffle` so the funds
+    /// cut off differ between runs.
+    pub max_runtime_secs: Option<u64>,
+    pub portfolio_mode: PortfolioMode,
+    /// Words a portfolio company name must contain one of. Empty means no
+    /// constraint in `linked` mode and `DEFAULT_PORTFOLIO_VOCABULARY` in
+    /// `vocabulary` mode.
+    pub portfolio_vocabulary: Vec<String>,
+    /// Write which strategy produced each field to `<out-dir>/provenance.jsonl`.
+    pub provenance: bool,
+}
+
+/// Line format of the `--log-file` logs. The terminal always gets text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    /// One JSON object per event, for log shippers.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => bail!("Unknown log format '{}', expected 'text' or 'json'", other),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            headless: true,
+            url: Vec::new(),
+            from_sitemap: None,
+            urls_file: None,
+            profile_url_pattern: DEFAULT_PROFILE_URL_PATTERN.to_string(),
+            columns: FIELD_NAMES.to_vec(),
+            health_check_secs: 30,
+            max_open_pages: 4,
+            max_concurrent_navigations: 2,
+            list_load_timeout_secs: 30,
+            nav_timeout_secs: 30,
+            delay_ms: 2000,
+            page_wait_ms: 3000,
+            out_dir: "data".to_string(),
+            basename: "vestbee_funds".to_string(),
+            rows_per_file: None,
+            json_path: None,
+            json_case: JsonCase::default(),
+            jsonl: false,
+            sqlite: None,
+            writer_concurrency: 1,
+            confidence_columns: false,
+            quiet: false,
+            verbose: false,
+            details_label: Vec::new(),
+            include_empty: false,
+            portfolio_roster: None,
+            graph_path: None,
+            emit_raw: None,
+            gzip: false,
+            chrome_arg: Vec::new(),
+            chrome_path: None,
+            locale: DEFAULT_LOCALE.to_string(),
+            fsync: false,
+            csv_summary: false,
+            max_rps: None,
+            preflight: false,
+            dry_run: false,
+            conditional_cache: None,
+            log_file: None,
+            log_format: LogFormat::default(),
+            duplicate_descriptions: false,
+            resume_discovery: false,
+            resume: false,
+            limit: None,
+            merge_into: None,
+            s3_bucket: None,
+            s3_prefix: String::new(),
+            shuffle: false,
+            seed: None,
+            desc_max_len: DEFAULT_DESC_MAX_LEN,
+            desc_min_len: DEFAULT_DESC_MIN_LEN,
+            global_retry_budget: None,
+            concurrency: 4,
+            strict: false,
+            required_fields: vec!["fund_name", "fund_url"],
+            max_rejected: None,
+            contact_email: None,
+            headers: Vec::new(),
+            preview: None,
+            maintenance_marker: Vec::new(),
+            redact_pii: false,
+            max_runtime_secs: None,
+            portfolio_mode: PortfolioMode::default(),
+            portfolio_vocabulary: Vec::new(),
+            provenance: false,
+        }
+    }
+}
+
+impl Config {
+    /// Builds the configuration from the options given on the command line,
+    /// layered as defaults < `--profile` < CLI flags < environment.
+    pub fn from_options(cli: Options) -> Result<Self> {
+        let mut config = Self::default();
+
+        let profile = cli.profile.clone().or_else(|| env::var("VESTBEE_PROFILE").ok());
+        if let Some(profile) = profile {
+            let path = cli.config.clone().unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
+            let source = format!("profile '{}' of {}", profile, path);
+            config.apply(Options::parse_layer(&profile_args(&path, &profile)?, &source)?)?;
+        }
+
+        config.apply(cli)?;
+        config.apply(Options::parse_layer(&env_args(), "VESTBEE_* environment variables")?)?;
+
+        // Fail before scraping rather than after, when the upload would run
+        if cfg!(not(feature = "s3")) && config.s3_bucket.is_some() {
+            bail!("--s3-bucket requires building with --features s3");
+        }
+
+        if config.from_sitemap.is_some() && !config.url.is_empty() {
+            bail!("--url and --from-sitemap can't be combined: funds are discovered from one or the other");
+        }
+
+        if config.urls_file.is_some() && (config.from_sitemap.is_some() || !config.url.is_empty()) {
+            bail!("--urls-file can't be combined with --url or --from-sitemap: it replaces discovery");
+        }
+
+        // Resuming appends to one plain CSV file
+        if config.resume && (config.gzip || config.rows_per_file.is_some()) {
+            bail!("--resume can't be combined with --gzip or --rows-per-file: only a single uncompressed CSV can be appended to");
+        }
+
+        if config.quiet && config.verbose {
+            bail!("--quiet and --verbose can't be combined");
+        }
+
+        if config.strict && config.include_empty {
+            bail!("--strict and --include-empty can't be combined: one rejects incomplete funds, the other keeps them");
+        }
+
+        if config.confidence_columns {
+            let confidence_columns = models::confidence_columns(&config.columns);
+            config.columns.extend(confidence_columns);
+        }
+
+        Ok(config)
+    }
+
+    /// Chromium flags with the leading `--` added where it was left off,
+    /// as in a profile's `chrome-arg = ["no-sandbox"]`.
+    pub fn chrome_args(&self) -> Vec<String> {
+        self.chrome_arg
+            .iter()
+            .map(|arg| {
+                if arg.starts_with("--") {
+                    arg.clone()
+                } else {
+                    format!("--{}", arg)
+                }
+            })
+            .collect()
+    }
+
+    /// The description length cap, `None` when uncapped.
+    pub fn desc_max_len(&self) -> Option<usize> {
+        (self.desc_max_len > 0).then_some(self.desc_max_len)
+    }
+
+    /// Headers sent with every page besides `Accept-Language`: `From` for
+    /// `--contact-email`, then the `--header`s in the order given.
+    pub fn extra_headers(&self) -> Vec<(String, String)> {
+        self.contact_email
+            .iter()
+            .map(|email| ("From".to_string(), email.clone()))
+            .chain(self.headers.iter().cloned())
+            .collect()
+    }
+
+    /// Button texts that mark a link to a fund profile on the list page.
+    pub fn details_labels(&self) -> Vec<String> {
+        if self.details_label.is_empty() {
+            DEFAULT_DETAILS_LABELS.iter().map(|label| label.to_string()).collect()
+        } else {
+            self.details_label.clone()
+        }
+    }
+
+    /// The portfolio vocabulary constraint in effect, empty for none.
+    pub fn portfolio_vocabulary(&self) -> Vec<String> {
+        if self.portfolio_vocabulary.is_empty() && self.portfolio_mode == PortfolioMode::Vocabulary {
+            DEFAULT_PORTFOLIO_VOCABULARY.iter().map(|word| word.to_string()).collect()
+        } else {
+            self.portfolio_vocabulary.clone()
+        }
+    }
+
+    /// `<out-dir>/<name>`, for the outputs with a fixed name.
+    pub fn output_path(&self, name: &str) -> String {
+        Path::new(&self.out_dir).join(name).to_string_lossy().into_owned()
+    }
+
+    /// `<out-dir>/<basename>.<extension>`, for the fund datasets.
+    pub fn dataset_path(&self, extension: &str) -> String {
+        self.output_path(&format!("{}.{}", self.basename, extension))
+    }
+
+    /// List pages funds are discovered from, in the order given.
+    pub fn list_urls(&self) -> Vec<String> {
+        if self.url.is_empty() {
+            vec![LP_LIST_URL.to_string()]
+        } else {
+            self.url.clone()
+        }
+    }
+
+    /// Texts that mark the site's maintenance page.
+    pub fn maintenance_markers(&self) -> Vec<String> {
+        if self.maintenance_marker.is_empty() {
+            DEFAULT_MAINTENANCE_MARKERS.iter().map(|marker| marker.to_string()).collect()
+        } else {
+            self.maintenance_marker.clone()
+        }
+    }
+
+    /// Overrides the settings `cli` sets, leaving the rest as they are.
+    /// Repeatable options add to what earlier layers gave.
+    fn apply(&mut self, cli: Options) -> Result<()> {
+        if let Some(headed) = cli.headed {
+            self.headless = !headed;
+        }
+        for url in cli.url {
+            if !reqwest::Url::parse(&url).is_ok_and(|parsed| matches!(parsed.scheme(), "http" | "https")) {
+                bail!("--url is not an http(s) URL: {}", url);
+            }
+            if !self.url.contains(&url) {
+                self.url.push(url);
+            }
+        }
+        set(&mut self.from_sitemap, cli.from_sitemap.map(Some));
+        set(&mut self.urls_file, cli.urls_file.map(Some));
+        set(&mut self.profile_url_pattern, cli.profile_url_pattern);
+        if let Some(columns) = cli.columns {
+            self.columns = models::parse_columns(&columns)?;
+        }
+        set(&mut self.health_check_secs, cli.health_check_secs);
+        if let Some(pages) = cli.max_open_pages {
+            if pages == 0 {
+                bail!("--max-open-pages must be greater than 0");
+            }
+            self.max_open_pages = pages;
+        }
+        if let Some(navigations) = cli.max_concurrent_navigations {
+            if navigations == 0 {
+                bail!("--max-concurrent-navigations must be greater than 0");
+            }
+            self.max_concurrent_navigations = navigations;
+        }
+        set(&mut self.list_load_timeout_secs, cli.list_load_timeout);
+        if let Some(secs) = cli.nav_timeout_secs {
+            if secs == 0 {
+                bail!("--nav-timeout-secs must be greater than 0");
+            }
+            self.nav_timeout_secs = secs;
+        }
+        set(&mut self.delay_ms, cli.delay_ms);
+        set(&mut self.page_wait_ms, cli.page_wait_ms);
+        set(&mut self.out_dir, cli.out_dir);
+        if let Some(basename) = cli.basename {
+            if basename.is_empty() || basename.contains(['/', '\\']) {
+                bail!("--basename must be a file name without a directory, got: {:?}", basename);
+            }
+            self.basename = basename;
+        }
+        if let Some(rows) = cli.rows_per_file {
+            if rows == 0 {
+                bail!("--rows-per-file must be greater than 0");
+            }
+            self.rows_per_file = Some(rows);
+        }
+        set(&mut self.json_path, cli.json.map(Some));
+        set(&mut self.json_case, cli.json_case);
+        set(&mut self.jsonl, cli.jsonl);
+        set(&mut self.sqlite, cli.sqlite.map(Some));
+        if let Some(writers) = cli.writer_concurrency {
+            if writers == 0 {
+                bail!("--writer-concurrency must be greater than 0");
+            }
+            self.writer_concurrency = writers;
+        }
+        set(&mut self.confidence_columns, cli.confidence_columns);
+        set(&mut self.quiet, cli.quiet);
+        set(&mut self.verbose, cli.verbose);
+        self.details_label.extend(cli.details_label);
+        set(&mut self.include_empty, cli.include_empty);
+        set(&mut self.portfolio_roster, cli.portfolio_roster.map(Some));
+        set(&mut self.graph_path, cli.graph.map(Some));
+        set(&mut self.emit_raw, cli.emit_raw.map(Some));
+        set(&mut self.gzip, cli.gzip);
+        self.chrome_arg.extend(cli.chrome_arg);
+        set(&mut self.chrome_path, cli.chrome_path.map(Some));
+        set(&mut self.locale, cli.locale);
+        set(&mut self.fsync, cli.fsync);
+        set(&mut self.csv_summary, cli.csv_summary);
+        set(&mut self.preflight, cli.preflight);
+        set(&mut self.dry_run, cli.dry_run);
+        set(&mut self.conditional_cache, cli.conditional_cache.map(Some));
+        set(&mut self.log_file, cli.log_file.map(Some));
+        set(&mut self.log_format, cli.log_format);
+        set(&mut self.duplicate_descriptions, cli.duplicate_descriptions);
+        set(&mut self.resume_discovery, cli.resume_discovery);
+        set(&mut self.resume, cli.resume);
+        // 0 means no limit, so a profile's limit can be lifted from the command line
+        set(&mut self.limit, cli.limit.map(|limit| Some(limit).filter(|limit| *limit > 0)));
+        set(&mut self.merge_into, cli.merge_into.map(Some));
+        set(&mut self.s3_bucket, cli.s3_bucket.map(Some));
+        set(&mut self.s3_prefix, cli.s3_prefix);
+        set(&mut self.shuffle, cli.shuffle);
+        set(&mut self.seed, cli.seed.map(Some));
+        set(&mut self.desc_max_len, cli.desc_max_len);
+        set(&mut self.desc_min_len, cli.desc_min_len);
+        set(&mut self.global_retry_budget, cli.global_retry_budget.map(Some));
+        if let Some(workers) = cli.concurrency {
+            if workers == 0 {
+                bail!("--concurrency must be greater than 0");
+            }
+            self.concurrency = workers;
+        }
+        set(&mut self.strict, cli.strict);
+        if let Some(fields) = cli.required_fields {
+            self.required_fields = models::parse_columns(&fields)?;
+        }
+        set(&mut self.max_rejected, cli.max_rejected.map(Some));
+        if let Some(email) = cli.contact_email {
+            if !is_plausible_email(&email) {
+                bail!("--contact-email is not an email address: {}", email);
+            }
+            self.contact_email = Some(email);
+        }
+        for header in cli.header {
+            self.headers.push(parse_header(&header)?);
+        }
+        set(&mut self.provenance, cli.provenance);
+        set(&mut self.portfolio_mode, cli.portfolio_mode);
+        self.portfolio_vocabulary.extend(cli.portfolio_vocabulary);
+        set(&mut self.max_runtime_secs, cli.max_runtime_secs.map(Some));
+        set(&mut self.redact_pii, cli.redact_pii);
+        self.maintenance_marker.extend(cli.maintenance_marker);
+        if let Some(funds) = cli.preview {
+            if funds == 0 {
+                bail!("--preview must be greater than 0");
+            }
+            self.preview = Some(funds);
+        }
+        if let Some(rps) = cli.max_rps {
+            if !(rps > 0.0 && rps.is_finite()) {
+                bail!("--max-rps must be a positive number");
+            }
+            self.max_rps = Some(rps);
+        }
+        // `profile` and `config` were resolved before any layer was applied
+
+        Ok(())
+    }
+}
+
+/// Overwrites `setting` with `value` when a layer gave one.
+fn set<T>(setting: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *setting = value;
+    }
+}
+
+/// Splits `Name: Value`, rejecting names that aren't an HTTP token and
+/// values that would break the header line.
+fn parse_header(header: &str) -> Result<(String, String)> {
+    let Some((name, value)) = header.split_once(':') else {
+        bail!("--header must look like \"Name: Value\", got: {}", header);
+    };
+    let name = name.trim();
+    let value = value.trim();
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c);
+    if name.is_empty() || !name.chars().all(is_token_char) {
+        bail!("Invalid header name in --header: {:?}", name);
+    }
+    if value.chars().any(|c| c.is_control() && c != '\t') {
+        bail!("Invalid header value in --header for {}: control characters aren't allowed", name);
+    }
+    Ok((name.to_string(), value.to_string()))
+}
+
+/// A light check that catches typos, not full address validation.
+fn is_plausible_email(email: &str) -> bool {
+    email
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.') && !email.contains(char::is_whitespace))
+}
+
+#[derive(Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    profiles: BTreeMap<String, toml::Table>,
+}
+
+/// Turns a named profile into `--key=value` arguments, so profiles accept
+/// exactly the same options as the command line:
+///
+/// ```toml
+/// [profiles.debug-headed]
+/// headed = true
+/// columns = "fund_name,fund_url"
+/// ```
+fn profile_args(path: &str, name: &str) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path))?;
+    let file: ConfigFile =
+        toml::from_str(&contents).with_context(|| format!("Failed to parse config file {}", path))?;
+
+    let Some(profile) = file.profiles.get(name) else {
+        let available: Vec<_> = file.profiles.keys().map(String::as_str).collect();
+        bail!(
+            "Unknown profile '{}' in {}. Available profiles: {}",
+            name,
+            path,
+            available.join(", ")
+        );
+    };
+
+    let mut args = Vec::new();
+    for (key, value) in profile {
+        let values = match value {
+            toml::Value::Array(items) => items.iter().collect(),
+            value => vec![value],
+        };
+        for value in values {
+            let value = match value {
+                toml::Value::String(s) => s.clone(),
+                toml::Value::Table(_) | toml::Value::Array(_) => {
+                    bail!("Unsupported value for '{}' in profile '{}'", key, name)
+                }
+                other => other.to_string(),
+            };
+            args.push(format!("--{}={}", key, value));
+        }
+    }
+    Ok(args)
+}
+
+/// Turns `VESTBEE_*` environment variables into `--key=value` arguments.
+fn env_args() -> Vec<String> {
+    env::vars()
+        .filter_map(|(key, value)| {
+            let name = key.strip_prefix(ENV_PREFIX)?;
+            if name == "PROFILE" {
+                return None;
+            }
+            Some(format!("--{}={}", name.to_lowercase().replace('_', "-"), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    /// The configuration for `args` given on the command line.
+    fn config(args: &[String]) -> Result<Config> {
+        Config::from_options(Options::try_parse_from(program_args(args))?)
+    }
+
+    fn profile_file() -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(
+            br#"
+            [profiles.debug]
+            headed = true
+            delay-ms = 10
+            concurrency = 2
+            chrome-arg = ["no-sandbox"]
+            "#,
+        )
+        .unwrap();
+        file
+    }
+
+    #[test]
+    fn defaults_apply_without_options() {
+        let config = config(&[]).unwrap();
+        assert!(config.headless);
+        assert_eq!(config.delay_ms, 2000);
+        assert_eq!(config.max_open_pages, 4);
+        assert_eq!(config.list_urls(), [LP_LIST_URL]);
+    }
+
+    #[test]
+    fn command_line_overrides_the_profile() {
+        let file = profile_file();
+        let path = file.path().to_str().unwrap();
+        let config = config(&args(&[
+            "--profile",
+            "debug",
+            "--config",
+            path,
+            "--delay-ms=500",
+            "--headed=false",
+            "--chrome-arg",
+            "--disable-gpu",
+        ]))
+        .unwrap();
+        assert!(config.headless);
+        assert_eq!(config.delay_ms, 500);
+        assert_eq!(config.concurrency, 2);
+        // Repeatable options add to the profile's
+        assert_eq!(config.chrome_args(), ["--no-sandbox", "--disable-gpu"]);
+    }
+
+    #[test]
+    fn layers_reject_invalid_values() {
+        assert!(config(&args(&["--max-rps", "-1"])).is_err());
+        assert!(config(&args(&["--basename", "out/funds"])).is_err());
+        assert!(Options::parse_layer(&args(&["--delay-ms=soon"]), "a test").is_err());
+        assert!(Options::parse_layer(&args(&["--no-such-flag"]), "a test").is_err());
+    }
+}
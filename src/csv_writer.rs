@@ -1,49 +1,414 @@
-use anyhow::Result;
-use csv::Writer;
-use std::fs::File;
+use anyhow::{anyhow, bail, Context, Result};
+use csv::{ReaderBuilder, Writer};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use tracing::{info, warn};
 
-use crate::models::Fund;
+use crate::models::{Fund, CONFIDENCE_COLUMNS, FIELD_NAMES};
+use crate::normalize;
+use crate::output::{output_path, part_path, OutputFile};
 
 pub struct CsvExporter {
-    writer: Writer<File>,
+    writer: Writer<OutputFile>,
+    columns: Vec<&'static str>,
+    path: PathBuf,
+    rows_per_file: Option<usize>,
+    rows_in_file: usize,
+    part: usize,
+    /// Every file written so far, in order.
+    paths: Vec<PathBuf>,
+    gzip: bool,
+    fsync: bool,
+    /// Append a totals row on `finalize`, see `with_summary`.
+    summary: bool,
+    fund_count: usize,
+    /// Summed AUM by currency code, empty for figures without a currency.
+    aum_totals: BTreeMap<String, f64>,
 }
 
 impl CsvExporter {
-    pub fn new(filename: &str) -> Result<Self> {
-        let writer = Writer::from_path(filename)?;
-        Ok(Self { writer })
+    /// Creates the exporter. With `rows_per_file` set, output is split into
+    /// `<name>_0001.csv`, `<name>_0002.csv`, ... each with its own header.
+    /// With `gzip` every file is compressed and gets a `.gz` suffix, and with
+    /// `fsync` each row is synced to disk before `write_fund` returns.
+    pub fn new(
+        filename: &str,
+        columns: &[&'static str],
+        rows_per_file: Option<usize>,
+        gzip: bool,
+        fsync: bool,
+    ) -> Result<Self> {
+        let path = PathBuf::from(filename);
+        let part = 1;
+        let first_path = match rows_per_file {
+            Some(_) => part_path(&path, part),
+            None => path.clone(),
+        };
+        let first_path = output_path(&first_path, gzip);
+        let writer = Writer::from_writer(OutputFile::create(&first_path, gzip)?);
+        Ok(Self {
+            writer,
+            columns: columns.to_vec(),
+            path,
+            rows_per_file,
+            rows_in_file: 0,
+            part,
+            paths: vec![first_path],
+            gzip,
+            fsync,
+            summary: false,
+            fund_count: 0,
+            aum_totals: BTreeMap::new(),
+        })
+    }
+
+    /// Reopens the single, uncompressed CSV of an interrupted run to add
+    /// rows to it, and returns the funds it already holds. A missing or
+    /// empty file gets a header first; a file written with other columns is
+    /// refused, as the new rows wouldn't line up with its header. Rows that
+    /// can't be read are left in place and not returned.
+    pub fn open_append(filename: &str, columns: &[&'static str], fsync: bool) -> Result<(Self, Vec<Fund>)> {
+        let path = PathBuf::from(filename);
+        let has_header = fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0);
+
+        let mut funds = Vec::new();
+        if has_header {
+            let existing = read_funds(filename)?;
+            if existing.columns != columns {
+                bail!(
+                    "{} was written with the columns {}, not {}; pass the same --columns to resume it",
+                    filename,
+                    existing.columns.join(","),
+                    columns.join(",")
+                );
+            }
+            for (line, fund) in existing.rows {
+                match fund {
+                    Ok(fund) => funds.push(fund),
+                    Err(e) => warn!("Line {} of {} can't be read, its fund will be scraped again: {}", line, filename, e),
+                }
+            }
+        }
+
+        let writer = Writer::from_writer(OutputFile::append(&path)?);
+        let mut exporter = Self {
+            writer,
+            columns: columns.to_vec(),
+            path: path.clone(),
+            rows_per_file: None,
+            rows_in_file: funds.len(),
+            part: 1,
+            paths: vec![path],
+            gzip: false,
+            fsync,
+            summary: false,
+            fund_count: 0,
+            aum_totals: BTreeMap::new(),
+        };
+        for fund in &funds {
+            exporter.count(fund);
+        }
+        if !has_header {
+            exporter.write_header()?;
+        }
+        Ok((exporter, funds))
+    }
+
+    /// Ends the output with a footer row holding the fund count in the name
+    /// column (or the first column) and the summed AUM in the AUM column,
+    /// totalled per currency when they differ.
+    /// The footer is not a fund, so consumers that expect every row to be
+    /// one have to drop the last row; that's why it is off by default. With
+    /// `rows_per_file` the footer goes into the last file and totals all files.
+    pub fn with_summary(mut self, summary: bool) -> Self {
+        self.summary = summary;
+        self
     }
 
     pub fn write_header(&mut self) -> Result<()> {
-        self.writer.write_record(&[
-            "fund_name",
-            "fund_url",
-            "AUM (€)",
-            "linkedin_url",
-            "investment_geographies",
-            "fund_description",
-            "fund_portfolio",
-        ])?;
+        self.writer
+            .write_record(self.columns.iter().map(|column| header_for(column)))?;
         self.writer.flush()?;
         Ok(())
     }
 
     pub fn write_fund(&mut self, fund: &Fund) -> Result<()> {
-        self.writer.write_record(&[
-            &fund.fund_name,
-            &fund.fund_url,
-            &fund.aum,
-            &fund.linkedin_url,
-            &fund.investment_geographies,
-            &fund.fund_description,
-            &fund.fund_portfolio,
-        ])?;
+        if self.rows_per_file.is_some_and(|limit| self.rows_in_file >= limit) {
+            self.roll_over()?;
+        }
+
+        let values: Vec<_> = self
+            .columns
+            .iter()
+            .map(|column| fund.get_field(column).unwrap_or_default())
+            .collect();
+        self.writer.write_record(values.iter().map(|value| value.as_bytes()))?;
         self.writer.flush()?;
+        if self.fsync {
+            self.writer.get_ref().sync_all()?;
+        }
+        self.rows_in_file += 1;
+
+        self.count(fund);
         Ok(())
     }
 
-    pub fn finalize(mut self) -> Result<()> {
+    /// Adds the fund to the summary totals.
+    fn count(&mut self, fund: &Fund) {
+        self.fund_count += 1;
+        if let Some(aum) = fund.aum_value {
+            let currency = fund.aum_currency.clone().unwrap_or_default();
+            *self.aum_totals.entry(currency).or_default() += aum;
+        }
+    }
+
+    /// Finishes the output and returns the files written.
+    pub fn finalize(mut self) -> Result<Vec<PathBuf>> {
+        if self.summary {
+            self.write_summary()?;
+        }
+        finish(self.writer)?;
+        Ok(self.paths)
+    }
+
+    fn write_summary(&mut self) -> Result<()> {
+        let count_column = if self.columns.contains(&"fund_name") { "fund_name" } else { self.columns[0] };
+        let row: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| match *column {
+                column if column == count_column => format!("Total: {} funds", self.fund_count),
+                "aum" => self.aum_summary(),
+                _ => String::new(),
+            })
+            .collect();
+        self.writer.write_record(&row)?;
         self.writer.flush()?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// The AUM total, or one total per currency ("1500000 EUR; 250000 USD")
+    /// when funds report in different currencies, as those can't be added up.
+    fn aum_summary(&self) -> String {
+        match self.aum_totals.len() {
+            0 => String::new(),
+            1 => self.aum_totals.values().map(|total| normalize::format_amount(*total)).collect(),
+            _ => self
+                .aum_totals
+                .iter()
+                .map(|(currency, total)| format!("{} {}", normalize::format_amount(*total), currency).trim_end().to_string())
+                .collect::<Vec<_>>()
+                .join("; "),
+        }
+    }
+
+    fn roll_over(&mut self) -> Result<()> {
+        self.part += 1;
+        let path = output_path(&part_path(&self.path, self.part), self.gzip);
+        info!("Rolling CSV output over to {}", path.display());
+        let next = Writer::from_writer(OutputFile::create(&path, self.gzip)?);
+        finish(std::mem::replace(&mut self.writer, next))?;
+        self.paths.push(path);
+        self.rows_in_file = 0;
+        self.write_header()
+    }
+}
+
+fn finish(writer: Writer<OutputFile>) -> Result<()> {
+    writer
+        .into_inner()
+        .map_err(|e| anyhow::anyhow!("Failed to flush CSV output: {}", e.error()))?
+        .finish()
+}
+
+/// CSV header for a column; only the money columns differ from the field name.
+pub fn header_for(column: &str) -> &str {
+    match column {
+        "aum" => "AUM",
+        "fund_size" => "Fund size",
+        other => other,
+    }
+}
+
+/// The column a CSV header was written for, the inverse of `header_for`.
+/// Bare field names are accepted too, as in hand-edited files.
+pub fn column_for_header(header: &str) -> Option<&'static str> {
+    let header = header.trim();
+    FIELD_NAMES
+        .iter()
+        .chain(CONFIDENCE_COLUMNS.iter().map(|(_, confidence_column)| confidence_column))
+        .find(|column| header_for(column) == header || **column == header)
+        .copied()
+        // Files written before amounts kept their own currency
+        .or((header == "AUM (€)").then_some("aum"))
+        .or((header == "Fund size (€)").then_some("fund_size"))
+}
+
+/// Rows of a CSV written by `CsvExporter`, read back into funds.
+pub struct CsvRows {
+    /// Known columns present in the file, in file order.
+    pub columns: Vec<&'static str>,
+    /// Line number and the fund read from it, or why it couldn't be read.
+    pub rows: Vec<(u64, Result<Fund>)>,
+}
+
+/// Reads a CSV written by `CsvExporter`, from this or an older version.
+/// Columns are mapped by header, so columns the file lacks stay empty,
+/// unknown or repeated columns are skipped with a warning, and rows with
+/// missing trailing cells are read as far as they go. A malformed row or an
+/// unparseable value only fails its row. The `--csv-summary` footer is skipped.
+pub fn read_funds(path: &str) -> Result<CsvRows> {
+    let mut reader = ReaderBuilder::new()
+        .flexible(true)
+        .from_path(path)
+        .with_context(|| format!("Failed to read {}", path))?;
+
+    // The column each cell position holds, None for cells that are skipped
+    let mut mapping: Vec<Option<&'static str>> = Vec::new();
+    for header in reader.headers()? {
+        let column = match column_for_header(header) {
+            Some(column) if mapping.contains(&Some(column)) => {
+                warn!("Skipping repeated column '{}' in {}", header, path);
+                None
+            }
+            Some(column) => Some(column),
+            None => {
+                warn!("Skipping unknown column '{}' in {}", header, path);
+                None
+            }
+        };
+        mapping.push(column);
+    }
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map_or(0, |position| position.line());
+                rows.push((line, Err(anyhow!("Malformed row: {}", e))));
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, |position| position.line());
+
+        let url = mapping
+            .iter()
+            .position(|column| *column == Some("fund_url"))
+            .and_then(|position| record.get(position))
+            .unwrap_or_default();
+        if url.is_empty() && record.iter().any(|value| value.starts_with("Total: ")) {
+            continue;
+        }
+
+        rows.push((line, fund_from_record(&mapping, &record)));
+    }
+
+    Ok(CsvRows {
+        columns: mapping.into_iter().flatten().collect(),
+        rows,
+    })
+}
+
+fn fund_from_record(mapping: &[Option<&'static str>], record: &csv::StringRecord) -> Result<Fund> {
+    let mut fund = Fund::new();
+    for (column, value) in mapping.iter().zip(record.iter()) {
+        let Some(column) = column else { continue };
+        match column.strip_suffix("_confidence") {
+            Some(_) if value.is_empty() => {}
+            Some(field) => {
+                let confidence = value
+                    .parse()
+                    .map_err(|_| anyhow!("{}: not a number: {}", column, value))?;
+                fund.confidence.set(field, confidence);
+            }
+            None => {
+                fund.set_field(column, value.to_string())?;
+                // Typed fields drop what they can't parse; don't let that pass silently
+                if !value.is_empty() && fund.get_field(column).unwrap_or_default().is_empty() {
+                    return Err(anyhow!("{}: unparseable value: {}", column, value));
+                }
+            }
+        }
+    }
+    Ok(fund)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn csv_file(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn reads_an_old_five_column_file() {
+        let file = csv_file(
+            "fund_name,fund_url,AUM (€),investment_geographies,headquarters\n\
+             Acme Capital,https://www.vestbee.com/lp/acme-capital,1500000000,\"Europe, CEE\",Germany\n",
+        );
+        let csv = read_funds(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            csv.columns,
+            ["fund_name", "fund_url", "aum", "investment_geographies", "headquarters"]
+        );
+
+        let (line, fund) = csv.rows.into_iter().next().unwrap();
+        assert_eq!(line, 2);
+        let fund = fund.unwrap();
+        assert_eq!(fund.fund_name, "Acme Capital");
+        assert_eq!(fund.aum_value, Some(1_500_000_000.0));
+        assert_eq!(fund.investment_geographies, "Europe, CEE");
+        assert_eq!(fund.headquarters, "Germany");
+        // Columns added since are left empty
+        assert!(fund.ticket_size.is_empty());
+        assert_eq!(fund.deal_count, None);
+        assert!(fund.tags.is_empty());
+    }
+
+    #[test]
+    fn reads_every_column_of_the_current_fund() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+        let mut fund = Fund::new();
+        fund.fund_name = "Acme Capital".to_string();
+        fund.fund_url = "https://www.vestbee.com/lp/acme-capital".to_string();
+        fund.aum_value = Some(250_000_000.0);
+        fund.aum_currency = Some("USD".to_string());
+        fund.deal_count = Some(45);
+        fund.tags = vec!["Fintech".to_string(), "SaaS".to_string()];
+
+        let mut exporter = CsvExporter::new(path, FIELD_NAMES, None, false, false).unwrap();
+        exporter.write_header().unwrap();
+        exporter.write_fund(&fund).unwrap();
+        exporter.finalize().unwrap();
+
+        let csv = read_funds(path).unwrap();
+        assert_eq!(csv.columns, FIELD_NAMES);
+        let read = csv.rows.into_iter().next().unwrap().1.unwrap();
+        for column in FIELD_NAMES {
+            assert_eq!(read.get_field(column), fund.get_field(column), "{}", column);
+        }
+    }
+
+    #[test]
+    fn skips_unknown_and_repeated_columns() {
+        let file = csv_file(
+            "fund_url,legacy_score,fund_name,fund_name\n\
+             https://www.vestbee.com/lp/acme-capital,7,Acme Capital,Shadow\n\
+             https://www.vestbee.com/lp/short-row\n",
+        );
+        let csv = read_funds(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(csv.columns, ["fund_url", "fund_name"]);
+
+        let funds: Vec<Fund> = csv.rows.into_iter().map(|(_, fund)| fund.unwrap()).collect();
+        assert_eq!(funds[0].fund_name, "Acme Capital");
+        assert_eq!(funds[1].fund_url, "https://www.vestbee.com/lp/short-row");
+        assert!(funds[1].fund_name.is_empty());
+    }
+}
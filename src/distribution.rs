@@ -0,0 +1,82 @@
+use anyhow::Result;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use tracing::info;
+
+use crate::models::Fund;
+use crate::output::write_atomically;
+use crate::SUMMARY_TARGET;
+
+/// Key counting funds that had no value at all, so a broken extractor
+/// shows up as one dominant bucket.
+const NONE_KEY: &str = "(none)";
+
+/// How many of the most common values are logged per field.
+const TOP_N: usize = 10;
+
+/// Counts how often each value occurs. Every entry is one fund's joined
+/// field, split on `separator`; values are counted once per fund.
+pub fn tally<'a>(field_values: impl IntoIterator<Item = &'a str>, separator: char) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for field in field_values {
+        let mut values: Vec<&str> = field.split(separator).map(str::trim).filter(|v| !v.is_empty()).collect();
+        values.sort_unstable();
+        values.dedup();
+        if values.is_empty() {
+            values.push(NONE_KEY);
+        }
+        for value in values {
+            *counts.entry(value.to_string()).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Writes the per-geography and per-sector fund counts to `filename` and
+/// logs the most common values of each. Sectors come from the tags, which
+/// is where profiles list them.
+pub fn write_distribution(filename: &str, funds: &[Fund]) -> Result<()> {
+    let geographies = tally(funds.iter().map(|fund| fund.investment_geographies.as_str()), ',');
+    let tags: Vec<String> = funds.iter().map(|fund| fund.tags.join(";")).collect();
+    let sectors = tally(tags.iter().map(String::as_str), ';');
+
+    log_top("geographies", &geographies, funds.len());
+    log_top("sectors", &sectors, funds.len());
+
+    let document = json!({
+        "funds": funds.len(),
+        "geographies": geographies,
+        "sectors": sectors,
+    });
+    write_atomically(Path::new(filename), |tmp_path| {
+        let mut writer = BufWriter::new(File::create(tmp_path)?);
+        serde_json::to_writer_pretty(&mut writer, &document)?;
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+fn log_top(label: &str, counts: &BTreeMap<String, usize>, total: usize) {
+    let mut top: Vec<_> = counts.iter().collect();
+    // Most common first, ties alphabetical (the map order)
+    top.sort_by(|a, b| b.1.cmp(a.1));
+
+    let top = top
+        .into_iter()
+        .take(TOP_N)
+        .map(|(value, count)| format!("{} {} ({:.0}%)", value, count, percent(*count, total)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    info!(target: SUMMARY_TARGET, "Top {}: {}", label, top);
+}
+
+fn percent(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::models::Fund;
+use crate::output::write_atomically;
+
+/// Words per shingle. Three catches reworded templates without matching
+/// descriptions that merely share common phrases.
+const SHINGLE_WORDS: usize = 3;
+
+/// Jaccard similarity of shingle sets above which two descriptions count as
+/// near-identical.
+const SIMILARITY_THRESHOLD: f64 = 0.8;
+
+/// Shorter descriptions ("Venture capital fund.") match too easily to mean anything.
+const MIN_WORDS: usize = 8;
+
+/// Funds whose descriptions are near-identical, with one of the texts.
+#[derive(Serialize)]
+pub struct Cluster {
+    pub funds: Vec<ClusterFund>,
+    pub description: String,
+}
+
+#[derive(Serialize)]
+pub struct ClusterFund {
+    pub fund_name: String,
+    pub fund_url: String,
+}
+
+/// Groups funds whose descriptions share most of their word shingles, which
+/// flags templated boilerplate and extraction that grabbed a block shared by
+/// many pages. Similarity is transitive: A~B and B~C put all three together.
+pub fn find_clusters(funds: &[Fund]) -> Vec<Cluster> {
+    let shingles: Vec<Option<HashSet<u64>>> = funds
+        .iter()
+        .map(|fund| shingle(&fund.fund_description))
+        .collect();
+
+    let mut parent: Vec<usize> = (0..funds.len()).collect();
+    for (i, a) in shingles.iter().enumerate() {
+        let Some(a) = a else { continue };
+        for (j, b) in shingles.iter().enumerate().skip(i + 1) {
+            let Some(b) = b else { continue };
+            if jaccard(a, b) >= SIMILARITY_THRESHOLD {
+                let (root_i, root_j) = (find(&mut parent, i), find(&mut parent, j));
+                parent[root_j] = root_i;
+            }
+        }
+    }
+
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for i in 0..funds.len() {
+        let root = find(&mut parent, i);
+        groups.entry(root).or_default().push(i);
+    }
+
+    let mut clusters: Vec<Cluster> = groups
+        .into_values()
+        .filter(|members| members.len() > 1)
+        .map(|members| Cluster {
+            description: funds[members[0]].fund_description.clone(),
+            funds: members
+                .into_iter()
+                .map(|i| ClusterFund {
+                    fund_name: funds[i].fund_name.clone(),
+                    fund_url: funds[i].fund_url.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+    clusters.sort_by_key(|cluster| Reverse(cluster.funds.len()));
+    clusters
+}
+
+pub fn write_clusters(filename: &str, clusters: &[Cluster]) -> Result<()> {
+    write_atomically(Path::new(filename), |tmp_path| {
+        let mut writer = BufWriter::new(File::create(tmp_path)?);
+        serde_json::to_writer_pretty(&mut writer, clusters)?;
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+/// Hashed word shingles of the lowercased, punctuation-free text; None when
+/// the text is too short to compare.
+fn shingle(text: &str) -> Option<HashSet<u64>> {
+    let lowered = text.to_lowercase();
+    let words: Vec<&str> = lowered
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.len() < MIN_WORDS {
+        return None;
+    }
+
+    Some(
+        words
+            .windows(SHINGLE_WORDS)
+            .map(|window| {
+                let mut hasher = DefaultHasher::new();
+                window.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect(),
+    )
+}
+
+fn jaccard(a: &HashSet<u64>, b: &HashSet<u64>) -> f64 {
+    let intersection = a.intersection(b).count();
+    let union = a.len() + b.len() - intersection;
+    intersection as f64 / union as f64
+}
+
+/// Union-find root with path halving.
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
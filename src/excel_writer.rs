@@ -1,93 +1,190 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use rust_xlsxwriter::{Format, Workbook};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
 use crate::models::Fund;
+use crate::normalize::Currency;
+use crate::output::{part_path, write_atomically};
+
+/// Saves `funds` as one workbook, or with `rows_per_file` as numbered parts
+/// like the CSV (`vestbee_funds_0001.xlsx`, ...).
+///
+/// rust_xlsxwriter 0.64 has no constant-memory mode: a workbook keeps every
+/// cell in memory until it is saved, so one workbook costs memory in
+/// proportion to the text of all its funds. Each part is saved and dropped
+/// before the next is built, which bounds the extra memory to one part.
+pub fn save_workbooks(
+    filename: &str,
+    columns: &[&'static str],
+    funds: &[Fund],
+    rows_per_file: Option<usize>,
+) -> Result<Vec<PathBuf>> {
+    let Some(rows_per_file) = rows_per_file else {
+        let mut excel_writer = ExcelExporter::new(columns)?;
+        excel_writer.write_funds(funds)?;
+        return Ok(vec![excel_writer.save(filename)?]);
+    };
+
+    let mut paths = Vec::new();
+    for (part, chunk) in funds.chunks(rows_per_file).enumerate() {
+        let path = part_path(Path::new(filename), part + 1);
+        let mut excel_writer = ExcelExporter::new(columns)?;
+        excel_writer.write_funds(chunk)?;
+        paths.push(excel_writer.save(&path.to_string_lossy())?);
+    }
+    Ok(paths)
+}
 
 pub struct ExcelExporter {
     workbook: Workbook,
+    columns: Vec<&'static str>,
 }
 
 impl ExcelExporter {
-    pub fn new() -> Result<Self> {
+    pub fn new(columns: &[&'static str]) -> Result<Self> {
         Ok(Self {
             workbook: Workbook::new(),
+            columns: columns.to_vec(),
         })
     }
-    
+
     pub fn write_funds(&mut self, funds: &[Fund]) -> Result<()> {
         let worksheet = self.workbook.add_worksheet();
-        
+
         // Create header format: navy background, bold, white font
         let header_format = Format::new()
             .set_bold()
             .set_background_color(rust_xlsxwriter::Color::RGB(0x000080)) // Navy blue
             .set_font_color(rust_xlsxwriter::Color::White)
             .set_border(rust_xlsxwriter::FormatBorder::Thin);
-        
-        // Write headers
-        let headers = [
-            "Fund Name",
-            "Fund URL", 
-            "AUM (€)",
-            "LinkedIn URL",
-            "Investment Geographies",
-            "Fund Description",
-            "Fund Portfolio",
-        ];
-        
-        for (col, header) in headers.iter().enumerate() {
-            worksheet.write_with_format(0, col as u16, *header, &header_format)?;
+
+        // Write headers and set column widths for better readability
+        for (col, column) in self.columns.iter().enumerate() {
+            let (header, width) = column_layout(column);
+            worksheet.write_with_format(0, col as u16, header, &header_format)?;
+            worksheet.set_column_width(col as u16, width)?;
         }
-        
-        // Set column widths for better readability
-        worksheet.set_column_width(0, 30)?;  // Fund Name
-        worksheet.set_column_width(1, 50)?;  // Fund URL
-        worksheet.set_column_width(2, 15)?;  // AUM
-        worksheet.set_column_width(3, 40)?;  // LinkedIn URL
-        worksheet.set_column_width(4, 30)?;  // Geographies
-        worksheet.set_column_width(5, 60)?;  // Description
-        worksheet.set_column_width(6, 50)?;  // Portfolio
-        
+
         // Freeze the header row
         worksheet.set_freeze_panes(1, 0)?;
-        
+
         // Regular cell format with borders
         let cell_format = Format::new()
             .set_border(rust_xlsxwriter::FormatBorder::Thin);
-        
-        // Money format for AUM (euros with thousand separator, no decimals)
-        let money_format = Format::new()
-            .set_border(rust_xlsxwriter::FormatBorder::Thin)
-            .set_num_format("#,##0");
-        
+
+        // Money format: thousand separators, no decimals, and the symbol of
+        // the figure's currency when it is known
+        let money_format = |num_format: &str| {
+            Format::new()
+                .set_border(rust_xlsxwriter::FormatBorder::Thin)
+                .set_num_format(num_format)
+        };
+        let plain_money_format = money_format("#,##0");
+        let currency_formats: Vec<(Currency, Format)> = [Currency::Eur, Currency::Usd, Currency::Gbp]
+            .into_iter()
+            .map(|currency| (currency, money_format(&format!("\"{}\"#,##0", currency.symbol()))))
+            .collect();
+
         // Write all funds
         for (row_idx, fund) in funds.iter().enumerate() {
             let row = (row_idx + 1) as u32;  // +1 for header
-            
-            worksheet.write_with_format(row, 0, &fund.fund_name, &cell_format)?;
-            worksheet.write_with_format(row, 1, &fund.fund_url, &cell_format)?;
-            
-            // Write AUM as number if available
-            if !fund.aum.is_empty() {
-                if let Ok(aum_value) = fund.aum.parse::<f64>() {
-                    worksheet.write_with_format(row, 2, aum_value, &money_format)?;
-                } else {
-                    worksheet.write_with_format(row, 2, &fund.aum, &cell_format)?;
+
+            for (col, column) in self.columns.iter().enumerate() {
+                let col = col as u16;
+                let value = fund.get_field(column).unwrap_or_default();
+
+                // Write AUM and fund size as numbers if available
+                if *column == "aum" || *column == "fund_size" {
+                    if let Ok(amount) = value.parse::<f64>() {
+                        let currency = if *column == "aum" { &fund.aum_currency } else { &fund.fund_size_currency };
+                        let currency = currency.as_deref().and_then(Currency::from_code);
+                        let format = currency_formats
+                            .iter()
+                            .find(|(known, _)| Some(*known) == currency)
+                            .map_or(&plain_money_format, |(_, format)| format);
+                        worksheet.write_with_format(row, col, amount, format)?;
+                        continue;
+                    }
                 }
-            } else {
-                worksheet.write_with_format(row, 2, "", &cell_format)?;
+
+                // Confidence scores and counts are numbers too, so they sort and filter properly
+                if column.ends_with("_confidence") || *column == "deal_count" {
+                    if let Ok(confidence) = value.parse::<f64>() {
+                        worksheet.write_with_format(row, col, confidence, &cell_format)?;
+                        continue;
+                    }
+                }
+
+                worksheet.write_with_format(row, col, value.as_ref(), &cell_format)?;
             }
-            
-            worksheet.write_with_format(row, 3, &fund.linkedin_url, &cell_format)?;
-            worksheet.write_with_format(row, 4, &fund.investment_geographies, &cell_format)?;
-            worksheet.write_with_format(row, 5, &fund.fund_description, &cell_format)?;
-            worksheet.write_with_format(row, 6, &fund.fund_portfolio, &cell_format)?;
         }
-        
+
         Ok(())
     }
-    
-    pub fn save(mut self, filename: &str) -> Result<()> {
-        self.workbook.save(filename)?;
-        Ok(())
+
+    /// Saves the workbook and returns where it went. The workbook is only
+    /// built at the end of a run, so if `filename` can't be written it is
+    /// saved under its bare name in the working directory rather than lost.
+    pub fn save(mut self, filename: &str) -> Result<PathBuf> {
+        let path = Path::new(filename);
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            if let Err(e) = fs::create_dir_all(parent) {
+                warn!("Failed to create {}: {}", parent.display(), e);
+            }
+        }
+
+        let e = match write_atomically(path, |tmp_path| Ok(self.workbook.save(tmp_path)?)) {
+            Ok(()) => return Ok(path.to_path_buf()),
+            Err(e) => e,
+        };
+        let fallback = PathBuf::from(path.file_name().unwrap_or_default());
+        if fallback == path {
+            return Err(e);
+        }
+        warn!(
+            "Failed to save {} ({}), saving to {} in the working directory instead",
+            filename,
+            e,
+            fallback.display()
+        );
+        write_atomically(&fallback, |tmp_path| Ok(self.workbook.save(tmp_path)?))
+            .with_context(|| format!("Failed to save the workbook to {} too", fallback.display()))?;
+        Ok(fallback)
     }
-}
\ No newline at end of file
+}
+
+/// Header title and column width for each field.
+fn column_layout(column: &str) -> (&str, f64) {
+    match column {
+        "fund_name" => ("Fund Name", 30.0),
+        "name_source" => ("Name Source", 12.0),
+        "fund_url" => ("Fund URL", 50.0),
+        "source_list" => ("Source List", 30.0),
+        "aum" => ("AUM", 15.0),
+        "aum_raw" => ("AUM (raw)", 20.0),
+        "aum_currency" => ("AUM Currency", 12.0),
+        "fund_size" => ("Fund Size", 15.0),
+        "fund_size_currency" => ("Fund Size Currency", 12.0),
+        "ticket_size" => ("Ticket Size", 20.0),
+        "linkedin_url" => ("LinkedIn URL", 40.0),
+        "website_url" => ("Website", 40.0),
+        "contact_phone" => ("Contact Phone", 18.0),
+        "investment_geographies" => ("Investment Geographies", 30.0),
+        "investment_stages" => ("Investment Stages", 25.0),
+        "headquarters" => ("Headquarters", 20.0),
+        "status" => ("Status", 18.0),
+        "close_status" => ("Close Status", 16.0),
+        "close_date" => ("Close Date", 12.0),
+        "management_fee" => ("Management Fee", 16.0),
+        "carry" => ("Carry", 12.0),
+        "fund_description" => ("Fund Description", 60.0),
+        "fund_portfolio" => ("Fund Portfolio", 50.0),
+        "deal_count" => ("Deal Count", 12.0),
+        "tags" => ("Tags", 40.0),
+        "scraped_at" => ("Scraped At", 22.0),
+        other if other.ends_with("_confidence") => (other, 12.0),
+        other => (other, 20.0),
+    }
+}
@@ -0,0 +1,145 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+use tracing::error;
+
+use crate::csv_writer::CsvExporter;
+use crate::jsonl_writer::JsonlExporter;
+use crate::models::Fund;
+use crate::sqlite_writer::SqliteExporter;
+
+/// Funds buffered per writer before `ExportQueue::send` waits for one to
+/// be written. Small, so a stalled sink holds up scraping within a few
+/// funds instead of letting the backlog grow.
+const BUFFER_PER_WRITER: usize = 16;
+
+/// A destination funds are streamed to as they are scraped.
+#[async_trait]
+pub trait Exporter: Send {
+    async fn write_fund(&mut self, fund: &Fund) -> Result<()>;
+
+    /// Writer tasks this exporter can be fed by at once, capping
+    /// `--writer-concurrency`. Files take one fund at a time.
+    fn max_writers(&self) -> usize {
+        1
+    }
+
+    /// Finishes the output and returns the files written, if any.
+    async fn finish(self: Box<Self>) -> Result<Vec<PathBuf>>;
+}
+
+#[async_trait]
+impl Exporter for CsvExporter {
+    async fn write_fund(&mut self, fund: &Fund) -> Result<()> {
+        CsvExporter::write_fund(self, fund)
+    }
+
+    async fn finish(self: Box<Self>) -> Result<Vec<PathBuf>> {
+        self.finalize()
+    }
+}
+
+#[async_trait]
+impl Exporter for JsonlExporter {
+    async fn write_fund(&mut self, fund: &Fund) -> Result<()> {
+        JsonlExporter::write_fund(self, fund)
+    }
+
+    async fn finish(self: Box<Self>) -> Result<Vec<PathBuf>> {
+        self.finalize()
+    }
+}
+
+#[async_trait]
+impl Exporter for SqliteExporter {
+    async fn write_fund(&mut self, fund: &Fund) -> Result<()> {
+        SqliteExporter::write_fund(self, fund)
+    }
+
+    async fn finish(self: Box<Self>) -> Result<Vec<PathBuf>> {
+        self.finalize()
+    }
+}
+
+/// Hands funds to writer tasks over bounded channels, one channel per
+/// exporter, so scraping doesn't wait on each write. Every exporter gets
+/// every fund. Once a writer's buffer is full `send` waits, which slows
+/// scraping down to the slowest writer's pace rather than growing memory.
+pub struct ExportQueue {
+    senders: Vec<mpsc::Sender<Fund>>,
+    writers: Vec<JoinHandle<Result<Vec<PathBuf>>>>,
+}
+
+impl ExportQueue {
+    /// Starts the writer tasks for each exporter: `concurrency` of them
+    /// where the exporter accepts that many, otherwise as many as it does.
+    pub fn spawn(exporters: Vec<Box<dyn Exporter>>, concurrency: usize) -> Self {
+        let mut senders = Vec::with_capacity(exporters.len());
+        let mut writers = Vec::with_capacity(exporters.len());
+
+        for exporter in exporters {
+            let tasks = concurrency.min(exporter.max_writers()).max(1);
+            let (sender, receiver) = mpsc::channel::<Fund>(BUFFER_PER_WRITER * tasks);
+            senders.push(sender);
+
+            let receiver = Arc::new(Mutex::new(receiver));
+            let exporter = Arc::new(Mutex::new(exporter));
+            let tasks: Vec<JoinHandle<Result<()>>> = (0..tasks)
+                .map(|_| {
+                    let receiver = Arc::clone(&receiver);
+                    let exporter = Arc::clone(&exporter);
+                    tokio::spawn(async move {
+                        loop {
+                            // Released before writing, so another task can take the next fund
+                            let Some(fund) = receiver.lock().await.recv().await else {
+                                return Ok(());
+                            };
+                            if let Err(e) = exporter.lock().await.write_fund(&fund).await {
+                                error!("Export writer failed on {}: {}", fund.fund_url, e);
+                                // Stops the other tasks too, so `send` reports the failure
+                                receiver.lock().await.close();
+                                return Err(e);
+                            }
+                        }
+                    })
+                })
+                .collect();
+
+            writers.push(tokio::spawn(async move {
+                for task in tasks {
+                    task.await??;
+                }
+                let exporter = Arc::try_unwrap(exporter)
+                    .map_err(|_| anyhow!("Export writer still running after its tasks finished"))?
+                    .into_inner();
+                exporter.finish().await
+            }));
+        }
+
+        Self { senders, writers }
+    }
+
+    /// Queues a fund for every exporter, waiting while a buffer is full.
+    pub async fn send(&self, fund: Fund) -> Result<()> {
+        for sender in &self.senders {
+            sender
+                .send(fund.clone())
+                .await
+                .map_err(|_| anyhow!("Export writers stopped, see the error above"))?;
+        }
+        Ok(())
+    }
+
+    /// Waits for the queued funds to be written and returns every file written.
+    pub async fn finish(self) -> Result<Vec<PathBuf>> {
+        drop(self.senders);
+        let mut paths = Vec::new();
+        for writer in self.writers {
+            paths.extend(writer.await??);
+        }
+        Ok(paths)
+    }
+}
@@ -0,0 +1,2043 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chromiumoxide::Page;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::normalize;
+
+/// Labels (regex fragments) introducing a firm's assets under management.
+const AUM_LABELS: &[&str] = &["AUM", r"Assets\s*Under\s*Management"];
+
+/// Labels introducing the size of the fund currently being raised.
+const FUND_SIZE_LABELS: &[&str] = &[
+    r"Fund\s*size",
+    r"Target(?:\s*fund)?(?:\s*size)?",
+    "Raising",
+];
+
+/// The fund size labels trusted in running text. A bare "target" or
+/// "raising" there is more often about portfolio companies ("we target
+/// startups raising up to €2M") than about the fund.
+const FUND_SIZE_TEXT_LABELS: &[&str] = &[r"Fund\s*size", r"Target\s*(?:fund(?:\s*size)?|size)"];
+
+/// Labels introducing the typical investment per company.
+const TICKET_SIZE_LABELS: &[&str] = &[r"(?:Ticket|Check|Cheque|Investment)\s*sizes?", "Tickets?"];
+
+/// Labels introducing the annual management fee.
+const MANAGEMENT_FEE_LABELS: &[&str] = &[r"Management\s*fees?"];
+
+/// Labels introducing the carried interest.
+const CARRY_LABELS: &[&str] = &[r"Carried\s*interest", "Carry"];
+
+/// Nouns a stated deal count is written with, as in "120+ investments".
+const DEAL_COUNT_LABELS: &[&str] = &["investments", "deals", r"portfolio\s+companies"];
+
+/// Countries recognized as a fund's headquarters.
+const HQ_COUNTRIES: &[&str] = &[
+    "United States", "USA", "US", "United Kingdom", "UK",
+    "Germany", "France", "Spain", "Italy", "Poland",
+    "Ireland", "Netherlands", "Belgium", "Switzerland",
+    "Austria", "Sweden", "Norway", "Denmark", "Finland",
+    "Portugal", "Greece", "Czech Republic", "Hungary",
+    "Romania", "Bulgaria", "Croatia", "Serbia", "Slovenia",
+    "Estonia", "Latvia", "Lithuania", "Luxembourg",
+    "Canada", "Brazil", "China", "Japan", "India",
+    "Singapore", "Australia", "Israel", "Turkey", "Ukraine",
+];
+
+/// Labels introducing the regions a fund invests in.
+const GEOGRAPHY_LABELS: &[&str] = &[r"(?:Investment\s*)?Geograph(?:y|ies)", "Regions"];
+
+/// Labels introducing the investment stages.
+const STAGE_LABELS: &[&str] = &[r"(?:investment\s*)?stages?"];
+
+/// Labels introducing the investment status.
+const STATUS_LABELS: &[&str] = &[r"(?:investment\s*)?status"];
+
+/// Extracts one field of a fund profile from a loaded page.
+///
+/// The value comes back in its column text form (see `Fund::set_field`),
+/// tagged with the strategy that found it so confidence can be scored.
+#[async_trait]
+pub trait FieldExtractor: Send + Sync {
+    /// Column name of the field this extractor fills.
+    fn field(&self) -> &'static str;
+
+    /// CSS selector for content that renders after the rest of the page and
+    /// must be present before extracting. `None` extracts right away.
+    fn wait_for(&self) -> Option<&'static str> {
+        None
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted>;
+
+    /// Tidies a value taken from the page's structured data instead of
+    /// `extract`, applying the same cleaning `extract` would.
+    fn clean_structured(&self, value: String) -> String {
+        value
+    }
+}
+
+/// A loaded profile page along with its labeled facts and tables. These are
+/// read once per profile and shared by every extractor, so the DOM isn't
+/// walked again for each field.
+pub struct Profile<'a> {
+    pub page: &'a Page,
+    /// Label -> value pairs, see `extract_facts`.
+    pub facts: HashMap<String, String>,
+    pub tables: Vec<Table>,
+}
+
+impl<'a> Profile<'a> {
+    pub async fn read(page: &'a Page) -> Result<Self> {
+        Ok(Self {
+            page,
+            facts: extract_facts(page).await?,
+            tables: extract_tables(page).await?,
+        })
+    }
+}
+
+/// The extractors run for every profile, in the order they are applied.
+pub fn default_extractors(config: &Config) -> Vec<Box<dyn FieldExtractor>> {
+    vec![
+        Box::new(NameExtractor),
+        Box::new(GeographyExtractor),
+        Box::new(StageExtractor),
+        Box::new(AmountExtractor::aum()),
+        Box::new(AmountExtractor::fund_size()),
+        Box::new(TicketSizeExtractor),
+        Box::new(LinkedinExtractor),
+        Box::new(WebsiteExtractor),
+        Box::new(PhoneExtractor),
+        Box::new(DescriptionExtractor::new(config.desc_max_len(), config.desc_min_len)),
+        Box::new(PortfolioExtractor::new(config.portfolio_mode, config.portfolio_vocabulary())),
+        Box::new(HeadquartersExtractor),
+        Box::new(StatusExtractor),
+        Box::new(CloseExtractor),
+        Box::new(TagsExtractor),
+        Box::new(TermsExtractor::management_fee()),
+        Box::new(TermsExtractor::carry()),
+        Box::new(DealCountExtractor),
+    ]
+}
+
+/// Minimum Jaro-Winkler similarity for a heading to count as the same name
+/// as the page's meta title.
+const NAME_MATCH_THRESHOLD: f64 = 0.85;
+
+/// Separators between the fund name and the site name in page titles,
+/// as in "Alantra | Vestbee".
+const TITLE_SEPARATORS: &[&str] = &[" | ", " - ", " – ", " — "];
+
+/// Prefers the `og:title` meta tag, which holds the canonical fund name,
+/// over headings that sometimes turn out to be a section title ("About",
+/// "Portfolio"). Without `og:title` the heading is cross-checked against
+/// `<title>` and loses to it when the two disagree.
+pub struct NameExtractor;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NameCandidates {
+    heading: Extracted,
+    og_title: String,
+    page_title: String,
+}
+
+#[async_trait]
+impl FieldExtractor for NameExtractor {
+    fn field(&self) -> &'static str {
+        "fund_name"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const selectors = [
+                        ['h1', 'heading'],
+                        ['.fund-name', 'name_class'],
+                        ['.company-name', 'name_class'],
+                        ['.title', 'title_class'],
+                        ['[class*="name"]', 'partial_class']
+                    ];
+                    let heading = { value: '', strategy: 'none' };
+                    for (const [selector, strategy] of selectors) {
+                        const el = document.querySelector(selector);
+                        if (el && el.textContent) {
+                            heading = { value: el.textContent.trim(), strategy };
+                            break;
+                        }
+                    }
+
+                    const og = document.querySelector('meta[property="og:title"]');
+                    return {
+                        heading,
+                        ogTitle: ((og && og.getAttribute('content')) || '').trim(),
+                        pageTitle: (document.title || '').trim()
+                    };
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<NameCandidates>()?;
+
+        Ok(choose_name(candidates))
+    }
+}
+
+fn choose_name(candidates: NameCandidates) -> Extracted {
+    let NameCandidates { heading, og_title, page_title } = candidates;
+
+    let (title, strategy) = match (strip_site_name(&og_title), strip_site_name(&page_title)) {
+        (Some(og_title), _) => (og_title, "og_title"),
+        (None, Some(page_title)) => (page_title, "page_title"),
+        (None, None) => return heading,
+    };
+
+    let agree = names_agree(&heading.value, &title);
+    // <title> is noisier than og:title, so it only overrides a heading that disagrees
+    if strategy == "page_title" && agree {
+        return heading;
+    }
+    if !agree && !heading.value.is_empty() {
+        debug!("Name mismatch: heading '{}' vs {} '{}'", heading.value, strategy, title);
+    }
+
+    let mut name = Extracted::new(title, strategy);
+    name.raw = Some(heading.value).filter(|value| !value.is_empty());
+    name
+}
+
+/// "Alantra | Vestbee" -> "Alantra". None for an empty title.
+fn strip_site_name(title: &str) -> Option<String> {
+    let name = TITLE_SEPARATORS
+        .iter()
+        .filter_map(|separator| title.split_once(separator).map(|(name, _)| name))
+        .min_by_key(|name| name.len())
+        .unwrap_or(title)
+        .trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Whether a heading names the same thing as the meta title, allowing for
+/// suffixes like "Capital" or small spelling differences.
+fn names_agree(heading: &str, title: &str) -> bool {
+    let heading = heading.to_lowercase();
+    let title = title.to_lowercase();
+    if heading.is_empty() {
+        return false;
+    }
+    heading.contains(&title)
+        || title.contains(&heading)
+        || strsim::jaro_winkler(&heading, &title) >= NAME_MATCH_THRESHOLD
+}
+
+pub struct GeographyExtractor;
+
+#[async_trait]
+impl FieldExtractor for GeographyExtractor {
+    fn field(&self) -> &'static str {
+        "investment_geographies"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let facts = &profile.facts;
+        if let Some(raw) = fact_value(facts, GEOGRAPHY_LABELS)? {
+            let geographies = normalize::geographies(&raw);
+            if !geographies.is_empty() {
+                let mut extracted = Extracted::new(geographies.join(", "), "fact");
+                extracted.raw = Some(raw);
+                return Ok(extracted);
+            }
+        }
+
+        let geographies = profile.page
+            .evaluate(js_with_arg(
+                r#"
+                ((validGeoList) => {
+                    // Only actual location names count as geographies
+                    const validGeos = new Set(validGeoList);
+                    
+                    const foundGeos = new Set();
+                    let labeledMatch = false;
+                    const rawTexts = [];
+                    
+                    // Look for geography section specifically
+                    const allElements = Array.from(document.querySelectorAll('*'));
+                    
+                    for (const el of allElements) {
+                        const text = (el.textContent || '').trim();
+                        
+                        // Skip long text blocks and URLs
+                        if (text.length > 200 || text.includes('http') || text.includes('www.')) {
+                            continue;
+                        }
+                        
+                        // Look for labeled geography sections
+                        if (text.includes('Investment geography') || 
+                            text.includes('Geography') || 
+                            text.includes('Regions')) {
+                            
+                            rawTexts.push(text);
+
+                            // Split by common delimiters
+                            const parts = text.split(/[,;:\/\n]/);
+                            
+                            for (const part of parts) {
+                                const cleaned = part.trim();
+                                
+                                // Check if it's a valid geography
+                                for (const geo of validGeos) {
+                                    if (cleaned === geo || 
+                                        (cleaned.toLowerCase() === geo.toLowerCase())) {
+                                        foundGeos.add(geo);
+                                        labeledMatch = true;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    
+                    // Also check for standalone geography mentions in small text blocks
+                    for (const el of allElements) {
+                        const text = (el.textContent || '').trim();
+                        
+                        // Only check small text blocks
+                        if (text.length > 50 || text.includes('http')) {
+                            continue;
+                        }
+                        
+                        // Direct match with valid geographies
+                        for (const geo of validGeos) {
+                            if (text === geo || 
+                                (text.toLowerCase() === geo.toLowerCase() && text.length === geo.length)) {
+                                foundGeos.add(geo);
+                            }
+                        }
+                    }
+                    
+                    // Return unique geographies, excluding any with special characters or URLs
+                    const value = Array.from(foundGeos)
+                        .filter(g => !g.includes('/') && !g.includes('.') && !g.includes('Type'))
+                        .join(', ');
+                    return { value, strategy: labeledMatch ? 'labeled' : 'standalone', raw: rawTexts.join('\n') };
+                })(__ARG__)
+                "#,
+                &normalize::VALID_GEOGRAPHIES,
+            )?)
+            .await?
+            .into_value::<Extracted>()?;
+
+        Ok(geographies)
+    }
+}
+
+/// The stages a fund invests at, kept only when they are in
+/// `normalize::INVESTMENT_STAGES` so section text doesn't leak in.
+pub struct StageExtractor;
+
+#[async_trait]
+impl FieldExtractor for StageExtractor {
+    fn field(&self) -> &'static str {
+        "investment_stages"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let facts = &profile.facts;
+        let fact = fact_value(facts, STAGE_LABELS)?.map(|value| Extracted::new(value, "fact"));
+
+        // Stages are often shown as chips under a bare "Stage" label
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                    const label = /^(investment\s*)?stages?:?$/i;
+                    const candidates = [];
+
+                    for (const el of document.querySelectorAll('body *')) {
+                        if (el.children.length > 0 || !label.test(clean(el.textContent))) {
+                            continue;
+                        }
+                        if (el.nextElementSibling) {
+                            const chips = Array.from(el.nextElementSibling.querySelectorAll('*'))
+                                .filter(chip => chip.children.length === 0)
+                                .map(chip => clean(chip.textContent));
+                            const value = chips.length > 0 ? chips.join(', ') : clean(el.nextElementSibling.textContent);
+                            candidates.push({ value, strategy: 'labeled' });
+                        }
+                        const section = el.parentElement;
+                        if (section) {
+                            const chips = Array.from(section.querySelectorAll('*'))
+                                .filter(chip => chip !== el && chip.children.length === 0)
+                                .map(chip => clean(chip.textContent));
+                            candidates.push({ value: chips.join(', '), strategy: 'section' });
+                        }
+                    }
+
+                    return candidates;
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        for candidate in fact.into_iter().chain(candidates) {
+            let stages = normalize::stages(&candidate.value);
+            if !stages.is_empty() {
+                let mut extracted = Extracted::new(stages.join(", "), &candidate.strategy);
+                extracted.raw = Some(candidate.value);
+                return Ok(extracted);
+            }
+        }
+        Ok(Extracted::none())
+    }
+}
+
+/// Finds a labeled money figure and normalizes it to whole units.
+pub struct AmountExtractor {
+    field: &'static str,
+    labels: &'static [&'static str],
+    /// Labels trusted for figures in running text, stricter than `labels`
+    /// where a label word is common in prose.
+    text_labels: &'static [&'static str],
+    /// Field recording the currency the figure was written in, if tracked.
+    currency_field: Option<&'static str>,
+    /// Field keeping the matched text as written, if tracked.
+    raw_field: Option<&'static str>,
+    /// Take the largest figure on the page when none is labeled.
+    fallback_to_largest: bool,
+}
+
+impl AmountExtractor {
+    /// Firm-wide assets under management.
+    pub fn aum() -> Self {
+        Self {
+            field: "aum",
+            labels: AUM_LABELS,
+            text_labels: AUM_LABELS,
+            currency_field: Some("aum_currency"),
+            raw_field: Some("aum_raw"),
+            fallback_to_largest: true,
+        }
+    }
+
+    /// Size of the fund currently being raised, kept separate from firm-wide AUM.
+    pub fn fund_size() -> Self {
+        Self {
+            field: "fund_size",
+            labels: FUND_SIZE_LABELS,
+            text_labels: FUND_SIZE_TEXT_LABELS,
+            currency_field: Some("fund_size_currency"),
+            raw_field: None,
+            fallback_to_largest: false,
+        }
+    }
+}
+
+#[async_trait]
+impl FieldExtractor for AmountExtractor {
+    fn field(&self) -> &'static str {
+        self.field
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        // Labeled facts and table cells are unambiguous, so they win over
+        // figures found in running text
+        let facts = &profile.facts;
+        let mut chosen = fact_value(facts, self.labels)?
+            .and_then(|raw| normalize::parse_amount(&raw).map(|amount| (raw, amount, "fact")));
+        if chosen.is_none() {
+            let tables = &profile.tables;
+            chosen = table_values(tables, self.labels)?
+                .into_iter()
+                .find_map(|raw| normalize::parse_amount(&raw).map(|amount| (raw, amount, "table")));
+        }
+        if chosen.is_none() {
+            let candidates = extract_amount_candidates(profile.page).await?;
+            chosen = choose_amount(&candidates, self.text_labels, self.fallback_to_largest)?;
+        }
+
+        let Some((raw, amount, strategy)) = chosen else {
+            return Ok(Extracted::none());
+        };
+        let mut extracted = Extracted::new(normalize::format_amount(amount), strategy);
+        extracted.raw = Some(raw.clone());
+        if let Some(currency_field) = self.currency_field {
+            // Recorded before any conversion so the figure's currency is never lost
+            let currency = normalize::detect_currency(&raw).map(|currency| currency.code());
+            extracted
+                .related
+                .insert(currency_field.to_string(), currency.unwrap_or_default().to_string());
+        }
+        if let Some(raw_field) = self.raw_field {
+            extracted.related.insert(raw_field.to_string(), raw);
+        }
+        Ok(extracted)
+    }
+}
+
+/// The typical check size, kept as written ("€250k–€2M") since ranges
+/// don't reduce to one amount.
+pub struct TicketSizeExtractor;
+
+#[async_trait]
+impl FieldExtractor for TicketSizeExtractor {
+    fn field(&self) -> &'static str {
+        "ticket_size"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let facts = &profile.facts;
+        let mut candidates: Vec<Extracted> = fact_value(facts, TICKET_SIZE_LABELS)?
+            .map(|value| Extracted::new(value, "fact"))
+            .into_iter()
+            .collect();
+        let tables = &profile.tables;
+        candidates.extend(
+            table_values(tables, TICKET_SIZE_LABELS)?
+                .into_iter()
+                .map(|value| Extracted::new(value, "table")),
+        );
+
+        // "Ticket size €250k–€2M" in one element, or a label and its value
+        let labeled = profile.page
+            .evaluate(js_with_arg(
+                r#"
+                ((labels) => {
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                    const inline = new RegExp('^(?:' + labels.join('|') + ')\\s*[:\\-–]?\\s*(.+)$', 'i');
+                    const bare = new RegExp('^(?:' + labels.join('|') + '):?$', 'i');
+                    const candidates = [];
+
+                    for (const el of document.querySelectorAll('body *')) {
+                        if (el.children.length > 2) {
+                            continue;
+                        }
+                        const text = clean(el.textContent);
+                        if (!text || text.length > 120) {
+                            continue;
+                        }
+                        if (bare.test(text)) {
+                            if (el.nextElementSibling) {
+                                candidates.push({ value: clean(el.nextElementSibling.textContent), strategy: 'labeled' });
+                            }
+                            continue;
+                        }
+                        const match = text.match(inline);
+                        if (match) {
+                            candidates.push({ value: match[1], strategy: 'inline' });
+                        }
+                    }
+
+                    return candidates;
+                })(__ARG__)
+                "#,
+                &TICKET_SIZE_LABELS,
+            )?)
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        for candidate in candidates.into_iter().chain(labeled) {
+            if let Some(ticket_size) = normalize::ticket_size(&candidate.value) {
+                let mut extracted = Extracted::new(ticket_size, &candidate.strategy);
+                extracted.raw = Some(candidate.value);
+                return Ok(extracted);
+            }
+        }
+        Ok(Extracted::none())
+    }
+}
+
+pub struct LinkedinExtractor;
+
+#[async_trait]
+impl FieldExtractor for LinkedinExtractor {
+    fn field(&self) -> &'static str {
+        "linkedin_url"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let linkedin_url = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    // Find LinkedIn links
+                    const links = Array.from(document.querySelectorAll('a[href*="linkedin.com"]'));
+                    for (const link of links) {
+                        const href = link.href || '';
+                        if (href.includes('linkedin.com/company/') || href.includes('linkedin.com/in/')) {
+                            return { value: href, strategy: 'profile_link' };
+                        }
+                    }
+                    
+                    // Check for LinkedIn in social media sections
+                    const socialLinks = Array.from(document.querySelectorAll('[class*="social"] a, [class*="Social"] a, footer a'));
+                    for (const link of socialLinks) {
+                        const href = link.href || '';
+                        if (href.includes('linkedin.com')) {
+                            return { value: href, strategy: 'social_section' };
+                        }
+                    }
+                    
+                    // Check for LinkedIn icon links
+                    const iconLinks = Array.from(document.querySelectorAll('a[aria-label*="LinkedIn"], a[title*="LinkedIn"]'));
+                    for (const link of iconLinks) {
+                        const href = link.href || '';
+                        if (href) {
+                            return { value: href, strategy: 'icon_link' };
+                        }
+                    }
+                    
+                    return { value: '', strategy: 'none' };
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<Extracted>()?;
+
+        Ok(linkedin_url)
+    }
+}
+
+/// Finds the fund's own website: a link labeled "Website" or "Visit
+/// website" first, else the first outbound link. Candidates on a social
+/// network or Vestbee itself are skipped by `normalize::website`.
+pub struct WebsiteExtractor;
+
+#[async_trait]
+impl FieldExtractor for WebsiteExtractor {
+    fn field(&self) -> &'static str {
+        "website_url"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const labeled = /^(?:visit\s+)?(?:our\s+)?(?:web\s*site|homepage)$/i;
+                    const label = (link) => [link.textContent, link.getAttribute('aria-label'), link.getAttribute('title')]
+                        .map(text => (text || '').trim().replace(/\s+/g, ' '));
+                    // Resolved hrefs only: mailto:, tel: and javascript: links aren't websites
+                    const links = Array.from(document.querySelectorAll('a[href]'))
+                        .filter(link => /^https?:/i.test(link.href));
+                    const candidates = [];
+
+                    for (const link of links) {
+                        if (label(link).some(text => labeled.test(text))) {
+                            candidates.push({ value: link.href, strategy: 'labeled' });
+                        }
+                    }
+                    for (const link of links) {
+                        if (link.hostname !== window.location.hostname) {
+                            candidates.push({ value: link.href, strategy: 'outbound_link' });
+                        }
+                    }
+
+                    return candidates;
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        Ok(candidates
+            .into_iter()
+            .find_map(|candidate| {
+                normalize::website(&candidate.value).map(|url| Extracted::new(url, &candidate.strategy))
+            })
+            .unwrap_or_else(Extracted::none))
+    }
+
+    fn clean_structured(&self, value: String) -> String {
+        normalize::website(&value).unwrap_or_default()
+    }
+}
+
+/// Collects the fund's phone numbers, preferring `tel:` links. Text only
+/// counts when labeled as a phone number or written in international form
+/// (`+44 20 ...`), so registration numbers and other digit runs are skipped.
+pub struct PhoneExtractor;
+
+#[async_trait]
+impl FieldExtractor for PhoneExtractor {
+    fn field(&self) -> &'static str {
+        "contact_phone"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                    const candidates = [];
+
+                    for (const link of document.querySelectorAll('a[href^="tel:"]')) {
+                        const number = decodeURIComponent(link.getAttribute('href').slice(4));
+                        candidates.push({ value: number, strategy: 'tel_link' });
+                    }
+
+                    const labeled = /^(?:phone|tel(?:ephone)?|call(?: us)?|mobile)\b[.:\s]*/i;
+                    const international = /(?:^|[^\w+])((?:\+|00)\d[\d\s().\/-]{5,20}\d)(?!\w)/g;
+                    for (const el of document.querySelectorAll('body *')) {
+                        if (el.children.length > 0 || el.closest('a[href^="tel:"]')) {
+                            continue;
+                        }
+                        const text = clean(el.textContent);
+                        if (!text || text.length > 80) {
+                            continue;
+                        }
+                        if (labeled.test(text)) {
+                            candidates.push({ value: text.replace(labeled, ''), strategy: 'labeled' });
+                            continue;
+                        }
+                        for (const match of text.matchAll(international)) {
+                            candidates.push({ value: match[1], strategy: 'inline' });
+                        }
+                    }
+
+                    return candidates;
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        // Candidates come in preference order, so the first valid one sets the strategy
+        let Some(strategy) = candidates
+            .iter()
+            .find(|candidate| normalize::phone(&candidate.value).is_some())
+            .map(|candidate| candidate.strategy.clone())
+        else {
+            return Ok(Extracted::none());
+        };
+        let numbers = normalize::phones(candidates.iter().map(|candidate| candidate.value.as_str()));
+        let mut extracted = Extracted::new(numbers.join(";"), &strategy);
+        extracted.raw = Some(
+            candidates
+                .iter()
+                .map(|candidate| candidate.value.as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        Ok(extracted)
+    }
+}
+
+/// Disclaimer appended to every profile, which is not part of any description.
+const BOILERPLATE: &str = "The material presented via this website is for informational purposes only. Nothing in this website constitutes a solicitation for the purchase or sale of any financial product or service. Material presented on this website does not constitute a public offering of securities or investment management services in any jurisdiction. Investing in startup and early stage companies involves risks, including loss of capital, illiquidity, lack of dividends and dilution, and it should be done only as part of a diversified portfolio. The Investments presented in this website are suitable only for investors who are sufficiently sophisticated to understand these risks and make their own investment decisions.";
+
+/// Start of the disclaimer, for cutting off variants and truncated copies.
+const BOILERPLATE_START: &str = "The material presented via this website";
+
+/// Description sections shorter than this are labels or stubs, not descriptions.
+const MIN_SECTION_LEN: usize = 50;
+
+/// Shortest description kept from a section once the disclaimer is removed.
+const MIN_DESCRIPTION_LEN: usize = 20;
+
+/// Default cap on description length in characters (`--desc-max-len`).
+pub const DEFAULT_DESC_MAX_LEN: usize = 1000;
+
+/// Default shortest paragraph used for a description (`--desc-min-len`).
+pub const DEFAULT_DESC_MIN_LEN: usize = 100;
+
+/// Takes the description from a dedicated section, or else joins the page's
+/// longer paragraphs, dropping the site-wide disclaimer.
+pub struct DescriptionExtractor {
+    /// Longest description kept, in characters; `None` keeps all of it.
+    max_len: Option<usize>,
+    /// Paragraphs up to this many characters are skipped as captions and labels.
+    min_paragraph_len: usize,
+}
+
+impl DescriptionExtractor {
+    pub fn new(max_len: Option<usize>, min_paragraph_len: usize) -> Self {
+        Self {
+            max_len,
+            min_paragraph_len,
+        }
+    }
+
+    /// Collapses whitespace, strips the disclaimer and applies the length cap.
+    fn clean(&self, text: &str) -> String {
+        let mut text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.contains(BOILERPLATE) {
+            text = text.replace(BOILERPLATE, "");
+        }
+        if let Some(start) = text.find(BOILERPLATE_START) {
+            text.truncate(start);
+        }
+        let text = text.trim();
+        match self.max_len {
+            Some(max_len) => text.chars().take(max_len).collect::<String>().trim_end().to_string(),
+            None => text.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DescriptionCandidates {
+    /// Text of the first element matching each description selector, in order.
+    sections: Vec<String>,
+    paragraphs: Vec<String>,
+}
+
+#[async_trait]
+impl FieldExtractor for DescriptionExtractor {
+    fn field(&self) -> &'static str {
+        "fund_description"
+    }
+
+    fn clean_structured(&self, value: String) -> String {
+        self.clean(&value)
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const selectors = ['.description', '.about', '.overview', '[class*="description"]', '[class*="about"]'];
+                    const sections = selectors
+                        .map(selector => document.querySelector(selector))
+                        .filter(el => el && el.textContent)
+                        .map(el => el.textContent.trim());
+                    const paragraphs = Array.from(document.querySelectorAll('p'))
+                        .map(p => (p.textContent || '').trim())
+                        .filter(text => text);
+                    return { sections, paragraphs };
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<DescriptionCandidates>()?;
+
+        for raw in candidates.sections {
+            if raw.chars().count() <= MIN_SECTION_LEN {
+                continue;
+            }
+            let text = self.clean(&raw);
+            if text.chars().count() > MIN_DESCRIPTION_LEN {
+                let mut description = Extracted::new(text, "selector");
+                description.raw = Some(raw);
+                return Ok(description);
+            }
+        }
+
+        let paragraphs = candidates
+            .paragraphs
+            .iter()
+            .filter(|text| text.chars().count() > self.min_paragraph_len && !text.contains(BOILERPLATE_START))
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if paragraphs.is_empty() {
+            return Ok(Extracted::none());
+        }
+        let mut description = Extracted::new(self.clean(&paragraphs), "paragraphs");
+        description.raw = Some(paragraphs);
+        Ok(description)
+    }
+}
+
+/// Words the `vocabulary` portfolio mode requires in a company name.
+pub const DEFAULT_PORTFOLIO_VOCABULARY: &[&str] = &["Ventures", "Capital", "Partners", "Fund", "Labs", "Accelerator"];
+
+/// Entries that are page furniture rather than companies (lowercase substrings).
+const PORTFOLIO_NOISE: &[&str] = &[
+    "cookies",
+    "material presented",
+    "website",
+    "investing in startup",
+    "see all",
+    "view all",
+    "show more",
+    "load more",
+];
+
+/// How portfolio companies are recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PortfolioMode {
+    /// Names of the linked entries in the profile's portfolio section,
+    /// whatever they are called, falling back to a "Portfolio: A, B" text.
+    #[default]
+    Linked,
+    /// Only names containing a vocabulary word such as "Ventures", the
+    /// original heuristic. It mostly finds co-investing firms rather than
+    /// startups, so it is kept for comparison with older datasets.
+    Vocabulary,
+}
+
+impl FromStr for PortfolioMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "linked" => Ok(Self::Linked),
+            "vocabulary" => Ok(Self::Vocabulary),
+            other => bail!("Unknown portfolio mode '{}', expected 'linked' or 'vocabulary'", other),
+        }
+    }
+}
+
+pub struct PortfolioExtractor {
+    mode: PortfolioMode,
+    /// Words a company name must contain one of; empty accepts any name.
+    vocabulary: Vec<String>,
+}
+
+impl PortfolioExtractor {
+    pub fn new(mode: PortfolioMode, vocabulary: Vec<String>) -> Self {
+        Self { mode, vocabulary }
+    }
+
+    fn is_company(&self, name: &str) -> bool {
+        let lower = name.to_lowercase();
+        (3..100).contains(&name.chars().count())
+            && !lower.starts_with("portfolio")
+            && !PORTFOLIO_NOISE.iter().any(|noise| lower.contains(noise))
+            && (self.vocabulary.is_empty() || self.vocabulary.iter().any(|word| name.contains(word.as_str())))
+    }
+}
+
+/// Portfolio names found on a profile, by where they were found.
+#[derive(Deserialize)]
+struct PortfolioCandidates {
+    /// Linked entries (or list items) inside the portfolio section.
+    linked: Vec<String>,
+    /// Names split out of "Portfolio: A, B; C" text.
+    inline: Vec<String>,
+    raw: Vec<String>,
+}
+
+#[async_trait]
+impl FieldExtractor for PortfolioExtractor {
+    fn field(&self) -> &'static str {
+        "fund_portfolio"
+    }
+
+    // Portfolio grids are filled in after the profile itself has rendered
+    fn wait_for(&self) -> Option<&'static str> {
+        Some(r#"[class*="portfolio"] li, [class*="portfolio"] a, [id*="portfolio"] li, [id*="portfolio"] a"#)
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                    const linked = [];
+                    const inline = [];
+                    const raw = [];
+
+                    // The portfolio section: a container named after it, or what follows its heading
+                    const containers = Array.from(document.querySelectorAll('[class*="portfolio"], [id*="portfolio"]'));
+                    const heading = Array.from(document.querySelectorAll('h2, h3, h4'))
+                        .find(el => /portfolio/i.test(el.textContent || ''));
+                    if (heading) {
+                        let sibling = heading.nextElementSibling;
+                        for (let count = 0; sibling && count < 5; count++) {
+                            containers.push(sibling);
+                            sibling = sibling.nextElementSibling;
+                        }
+                    }
+
+                    // Each entry is named by its link text, or a logo's alt/title when the link is just an image
+                    for (const container of containers) {
+                        const entries = container.querySelectorAll('a, li');
+                        for (const entry of entries) {
+                            if (entry.tagName === 'LI' && entry.querySelector('a')) {
+                                continue;
+                            }
+                            const img = entry.querySelector('img');
+                            const name = clean(entry.textContent) || clean(entry.getAttribute('title')) || (img ? clean(img.getAttribute('alt')) : '');
+                            if (name) {
+                                linked.push(name);
+                            }
+                        }
+                    }
+
+                    // "Portfolio: Company1, Company2" or "Portfolio Company1; Company2"
+                    for (const el of document.querySelectorAll('*')) {
+                        const text = el.textContent || '';
+                        if (!text.includes('Portfolio') || text.includes('portfolio management')) {
+                            continue;
+                        }
+                        const match = text.match(/Portfolio[:\s]+([^;]*(?:;[^;]*)*)/i);
+                        if (match && match[1]) {
+                            raw.push(match[1].trim());
+                            inline.push(...match[1].split(/[,;]/).map(clean));
+                        }
+                    }
+
+                    return { linked, inline, raw: Array.from(new Set(raw)) };
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<PortfolioCandidates>()?;
+
+        let keep = |names: &[String]| -> Vec<String> {
+            let mut kept: Vec<String> = Vec::new();
+            for name in names.iter().filter(|name| self.is_company(name)) {
+                if !kept.iter().any(|seen| seen.eq_ignore_ascii_case(name)) {
+                    kept.push(name.clone());
+                }
+            }
+            kept
+        };
+        let linked = keep(&candidates.linked);
+        let inline = keep(&candidates.inline);
+
+        let (companies, strategy) = match self.mode {
+            PortfolioMode::Linked if !linked.is_empty() => (linked, "section"),
+            PortfolioMode::Linked => (inline, "inline"),
+            // The original heuristic took both sources together
+            PortfolioMode::Vocabulary => {
+                let strategy = if linked.is_empty() { "inline" } else { "section" };
+                let mut companies = inline;
+                for name in linked {
+                    if !companies.iter().any(|seen| seen.eq_ignore_ascii_case(&name)) {
+                        companies.push(name);
+                    }
+                }
+                (companies, strategy)
+            }
+        };
+        if companies.is_empty() {
+            return Ok(Extracted::none());
+        }
+
+        let mut portfolio = Extracted::new(companies.join("; "), strategy);
+        portfolio.raw = Some(candidates.raw.join("\n")).filter(|raw| !raw.is_empty());
+        Ok(portfolio)
+    }
+}
+
+pub struct HeadquartersExtractor;
+
+#[async_trait]
+impl FieldExtractor for HeadquartersExtractor {
+    fn field(&self) -> &'static str {
+        "headquarters"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let headquarters = profile.page
+            .evaluate(js_with_arg(
+                r#"
+                ((countries) => {
+                    const labels = ['headquarters', 'hq', 'based in', 'location'];
+
+                    // Short names like "US" or "UK" must match case-sensitively to avoid "us"
+                    const findCountry = (text) => {
+                        for (const country of countries) {
+                            const flags = country.length <= 3 ? '' : 'i';
+                            if (new RegExp('\\b' + country + '\\b', flags).test(text)) {
+                                return country;
+                            }
+                        }
+                        return '';
+                    };
+
+                    // Prefer explicitly HQ-labeled text: "Headquarters: Warsaw, Poland" in one
+                    // element, or a label element followed by its value
+                    for (const el of document.querySelectorAll('*')) {
+                        const text = (el.textContent || '').trim();
+                        if (text.length > 100) {
+                            continue;
+                        }
+                        const lower = text.toLowerCase();
+                        const label = labels.find(l => lower.startsWith(l));
+                        if (!label) {
+                            continue;
+                        }
+
+                        let country = findCountry(text.slice(label.length));
+                        if (!country && el.nextElementSibling) {
+                            country = findCountry(el.nextElementSibling.textContent || '');
+                        }
+                        if (country) {
+                            return { value: country, strategy: 'labeled', raw: text };
+                        }
+                    }
+
+                    // Fall back to a postal address block
+                    for (const el of document.querySelectorAll('address, [class*="address"]')) {
+                        const country = findCountry(el.textContent || '');
+                        if (country) {
+                            return { value: country, strategy: 'address', raw: (el.textContent || '').trim() };
+                        }
+                    }
+
+                    return { value: '', strategy: 'none' };
+                })(__ARG__)
+                "#,
+                &HQ_COUNTRIES,
+            )?)
+            .await?
+            .into_value::<Extracted>()?;
+
+        Ok(headquarters)
+    }
+}
+
+/// Finds an explicit investment status, from a "Status" label or a badge,
+/// normalized to one of `normalize::INVESTMENT_STATUSES`.
+pub struct StatusExtractor;
+
+#[async_trait]
+impl FieldExtractor for StatusExtractor {
+    fn field(&self) -> &'static str {
+        "status"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const candidates = [];
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+
+                    // "Status: Actively investing" in one element, or a label followed by its value
+                    for (const el of document.querySelectorAll('*')) {
+                        const text = clean(el.textContent);
+                        if (text.length > 60 || !/^(investment\s+)?status\b/i.test(text)) {
+                            continue;
+                        }
+                        const inline = text.replace(/^(investment\s+)?status[:\s]*/i, '');
+                        if (inline) {
+                            candidates.push({ value: inline, strategy: 'labeled' });
+                        } else if (el.nextElementSibling) {
+                            candidates.push({ value: clean(el.nextElementSibling.textContent), strategy: 'labeled' });
+                        }
+                    }
+
+                    const badgeSelector = '[class*="status"], [class*="badge"], [class*="tag"], [class*="chip"], [class*="pill"]';
+                    for (const el of document.querySelectorAll(badgeSelector)) {
+                        const text = clean(el.textContent);
+                        if (text && text.length <= 40) {
+                            candidates.push({ value: text, strategy: 'chips' });
+                        }
+                    }
+
+                    return candidates;
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        let facts = &profile.facts;
+        let fact_candidate = fact_value(facts, STATUS_LABELS)?.map(|value| Extracted::new(value, "fact"));
+        let tables = &profile.tables;
+        let table_candidates = table_values(tables, STATUS_LABELS)?
+            .into_iter()
+            .map(|value| Extracted::new(value, "table"));
+
+        for candidate in fact_candidate.into_iter().chain(table_candidates).chain(candidates) {
+            if let Some(status) = normalize::status(&candidate.value) {
+                let mut extracted = Extracted::new(status.to_string(), &candidate.strategy);
+                extracted.raw = Some(candidate.value);
+                return Ok(extracted);
+            }
+        }
+        Ok(Extracted::none())
+    }
+}
+
+/// Labels introducing a private-equity fund's lifecycle stage.
+const CLOSE_LABELS: &[&str] = &[r"(?:fund\s*)?(?:close|closing)(?:\s*status)?", r"fundraising\s*status"];
+
+/// A private-equity fund's close status ("First close", "Final close", ...)
+/// and the date of that close, which goes into `close_date`. VC profiles
+/// rarely use this vocabulary and are left empty.
+pub struct CloseExtractor;
+
+#[async_trait]
+impl FieldExtractor for CloseExtractor {
+    fn field(&self) -> &'static str {
+        "close_status"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        // A fact labeled with the stage itself ("Final close: March 2023") or
+        // with a close label ("Fund close: First close, Q2 2023")
+        let facts = &profile.facts;
+        let mut fact_candidates: Vec<Extracted> = facts
+            .iter()
+            .filter(|(label, _)| normalize::close_status(label).is_some())
+            .map(|(label, value)| Extracted::new(format!("{}: {}", label, value), "fact"))
+            .collect();
+        fact_candidates.sort_by(|a, b| a.value.cmp(&b.value));
+        fact_candidates.extend(fact_value(facts, CLOSE_LABELS)?.map(|value| Extracted::new(value, "fact")));
+
+        let candidates = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                    const mentions = /\b(close|closing|closed|invested|committed)\b/i;
+                    const candidates = [];
+
+                    for (const el of document.querySelectorAll('body *')) {
+                        if (el.children.length > 2) {
+                            continue;
+                        }
+                        const text = clean(el.textContent);
+                        if (!text || text.length > 150 || !mentions.test(text)) {
+                            continue;
+                        }
+                        // A bare label like "Final close" takes its date from the next element
+                        const next = text.length <= 30 && el.nextElementSibling ? clean(el.nextElementSibling.textContent) : '';
+                        candidates.push({ value: next ? text + ': ' + next : text, strategy: 'labeled' });
+                    }
+
+                    return candidates;
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        for candidate in fact_candidates.into_iter().chain(candidates) {
+            if let Some(status) = normalize::close_status(&candidate.value) {
+                let mut extracted = Extracted::new(status.to_string(), &candidate.strategy);
+                extracted.related.insert(
+                    "close_date".to_string(),
+                    normalize::close_date(&candidate.value).unwrap_or_default(),
+                );
+                extracted.raw = Some(candidate.value);
+                return Ok(extracted);
+            }
+        }
+        Ok(Extracted::none())
+    }
+}
+
+/// The number of investments the profile states, as in "120+ investments"
+/// or "Deals: 45". Counting the portfolio entries is a separate fallback,
+/// applied in `scrape_fund_details`.
+pub struct DealCountExtractor;
+
+#[async_trait]
+impl FieldExtractor for DealCountExtractor {
+    fn field(&self) -> &'static str {
+        "deal_count"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let tables = &profile.tables;
+        let table_candidates = table_values(tables, DEAL_COUNT_LABELS)?
+            .into_iter()
+            .map(|value| Extracted::new(value, "table"));
+
+        let label = format!("(?:{})", DEAL_COUNT_LABELS.join("|"));
+        let candidates = profile.page
+            .evaluate(js_with_arg(
+                r#"
+                ((label) => {
+                    const exactLabel = new RegExp('^(?:number of\\s+)?' + label + '$', 'i');
+                    const mentionsLabel = new RegExp(label, 'i');
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                    const candidates = [];
+
+                    for (const el of document.querySelectorAll('body *')) {
+                        const text = clean(el.textContent);
+                        // A bare label followed by its number: "Investments" / "120+"
+                        if (exactLabel.test(text) && el.nextElementSibling) {
+                            candidates.push({ value: text + ': ' + clean(el.nextElementSibling.textContent), strategy: 'labeled' });
+                        } else if (text.length <= 60 && /\d/.test(text) && mentionsLabel.test(text)) {
+                            candidates.push({ value: text, strategy: 'inline' });
+                        }
+                    }
+
+                    return candidates;
+                })(__ARG__)
+                "#,
+                &label,
+            )?)
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        for candidate in table_candidates.chain(candidates) {
+            let count = if candidate.strategy == "table" {
+                parse_count(&candidate.value)
+            } else {
+                parse_deal_count(&candidate.value)?
+            };
+            if let Some(count) = count {
+                let mut extracted = Extracted::new(count.to_string(), &candidate.strategy);
+                extracted.raw = Some(candidate.value);
+                return Ok(extracted);
+            }
+        }
+        Ok(Extracted::none())
+    }
+}
+
+/// Reads the count from "120+ investments", "1,200 deals" or "Investments: 45".
+fn parse_deal_count(text: &str) -> Result<Option<u32>> {
+    let label = DEAL_COUNT_LABELS.join("|");
+    let number = r"(\d{1,3}(?:[,.]\d{3})+|\d+)";
+    let count_first = Regex::new(&format!(r"(?i){}\s*\+?\s*(?:{})\b", number, label))?;
+    let label_first = Regex::new(&format!(r"(?i)\b(?:{})\s*:\s*{}", label, number))?;
+
+    Ok(count_first
+        .captures(text)
+        .or_else(|| label_first.captures(text))
+        .and_then(|captures| parse_count(&captures[1])))
+}
+
+/// "1,200+" -> 1200.
+fn parse_count(text: &str) -> Option<u32> {
+    let digits: String = text
+        .trim()
+        .trim_end_matches('+')
+        .chars()
+        .filter(|c| !matches!(c, ',' | '.' | ' '))
+        .collect();
+    digits.parse().ok()
+}
+
+/// A fund term (fee, carry) from a labeled value, which few profiles disclose.
+/// Plain percentages are normalized; anything messier is kept as written.
+pub struct TermsExtractor {
+    field: &'static str,
+    labels: &'static [&'static str],
+}
+
+impl TermsExtractor {
+    pub fn management_fee() -> Self {
+        Self {
+            field: "management_fee",
+            labels: MANAGEMENT_FEE_LABELS,
+        }
+    }
+
+    pub fn carry() -> Self {
+        Self {
+            field: "carry",
+            labels: CARRY_LABELS,
+        }
+    }
+}
+
+#[async_trait]
+impl FieldExtractor for TermsExtractor {
+    fn field(&self) -> &'static str {
+        self.field
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let facts = &profile.facts;
+        let fact_candidate = fact_value(facts, self.labels)?.map(|value| Extracted::new(value, "fact"));
+        let tables = &profile.tables;
+        let table_candidates = table_values(tables, self.labels)?
+            .into_iter()
+            .map(|value| Extracted::new(value, "table"));
+
+        let label = format!("^(?:{})\\b[:\\s]*", self.labels.join("|"));
+        let candidates = profile.page
+            .evaluate(js_with_arg(
+                r#"
+                ((label) => {
+                    const labelRegex = new RegExp(label, 'i');
+                    const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                    const candidates = [];
+
+                    // "Carry: 20%" in one element, or a label followed by its value
+                    for (const el of document.querySelectorAll('*')) {
+                        const text = clean(el.textContent);
+                        if (text.length > 120 || !labelRegex.test(text)) {
+                            continue;
+                        }
+                        const inline = text.replace(labelRegex, '');
+                        if (inline) {
+                            candidates.push({ value: inline, strategy: 'labeled' });
+                        } else if (el.nextElementSibling) {
+                            candidates.push({ value: clean(el.nextElementSibling.textContent), strategy: 'labeled' });
+                        }
+                    }
+
+                    return candidates;
+                })(__ARG__)
+                "#,
+                &label,
+            )?)
+            .await?
+            .into_value::<Vec<Extracted>>()?;
+
+        // Terms are figures; a label word in running text ("carry out") isn't one
+        let term = fact_candidate
+            .into_iter()
+            .chain(table_candidates)
+            .chain(candidates)
+            .find(|candidate| candidate.value.len() <= 80 && candidate.value.contains(|c: char| c.is_ascii_digit()));
+
+        Ok(match term {
+            Some(term) => match normalize::normalize_percentage(&term.value) {
+                Some(percentage) => {
+                    let mut extracted = Extracted::new(percentage, &term.strategy);
+                    extracted.raw = Some(term.value);
+                    extracted
+                }
+                None => term,
+            },
+            None => Extracted::none(),
+        })
+    }
+}
+
+/// Collects the short labels rendered as tag/chip/badge/pill elements,
+/// which usually encode sectors, stages and themes.
+pub struct TagsExtractor;
+
+#[async_trait]
+impl FieldExtractor for TagsExtractor {
+    fn field(&self) -> &'static str {
+        "tags"
+    }
+
+    async fn extract(&self, profile: &Profile<'_>) -> Result<Extracted> {
+        let chips = profile.page
+            .evaluate(
+                r#"
+                (() => {
+                    const chipSelector = '[class*="tag"], [class*="chip"], [class*="badge"], [class*="pill"]';
+                    const seen = new Set();
+                    const chips = [];
+
+                    for (const el of document.querySelectorAll(chipSelector)) {
+                        // Skip wrappers around a list of chips, keep the chips themselves
+                        if (el.querySelector(chipSelector)) {
+                            continue;
+                        }
+
+                        const text = (el.textContent || '').trim().replace(/\s+/g, ' ');
+                        if (text.length < 2 || text.length > 40 || text.includes('http')) {
+                            continue;
+                        }
+
+                        const key = text.toLowerCase();
+                        if (!seen.has(key)) {
+                            seen.add(key);
+                            chips.push(text);
+                        }
+                    }
+
+                    return chips;
+                })()
+                "#,
+            )
+            .await?
+            .into_value::<Vec<String>>()?;
+
+        if chips.is_empty() {
+            Ok(Extracted::none())
+        } else {
+            Ok(Extracted::new(chips.join("; "), "chips"))
+        }
+    }
+}
+
+/// A `<table>` from the page, with cells as whitespace-normalized text.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Table {
+    /// Column headers, empty when the table has no header row.
+    #[serde(default)]
+    pub headers: Vec<String>,
+    /// Body rows, each a list of cells.
+    #[serde(default)]
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Cells labeled by `label`: the second cell of rows whose first cell
+    /// matches (key/value tables such as "Fund terms"), then the cells under
+    /// a matching column header.
+    pub fn values_for(&self, label: &Regex) -> Vec<&str> {
+        let mut values: Vec<&str> = self
+            .rows
+            .iter()
+            .filter(|row| row.len() >= 2 && label.is_match(&row[0]))
+            .map(|row| row[1].as_str())
+            .collect();
+
+        for record in self.records() {
+            for (header, cell) in record {
+                if label.is_match(header) {
+                    values.push(cell);
+                }
+            }
+        }
+
+        values.retain(|value| !value.is_empty());
+        values
+    }
+
+    /// The table as header -> cell maps, one per row; empty for tables
+    /// without a header row.
+    pub fn records(&self) -> Vec<BTreeMap<&str, &str>> {
+        if self.headers.is_empty() {
+            return Vec::new();
+        }
+        self.rows
+            .iter()
+            .map(|row| {
+                self.headers
+                    .iter()
+                    .zip(row)
+                    .map(|(header, cell)| (header.as_str(), cell.as_str()))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Parses every `<table>` on the page into rows of cell text, taking the
+/// header row from `<thead>` or a leading row made only of `<th>` cells.
+pub async fn extract_tables(page: &Page) -> Result<Vec<Table>> {
+    let tables = page
+        .evaluate(
+            r#"
+            (() => {
+                const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                const cells = (row) => Array.from(row.cells).map(cell => clean(cell.textContent));
+
+                return Array.from(document.querySelectorAll('table')).map(table => {
+                    let headerRow = table.tHead && table.tHead.rows.length ? table.tHead.rows[0] : null;
+                    const bodyRows = Array.from(table.rows).filter(row => !row.parentElement || row.parentElement.tagName !== 'THEAD');
+                    if (!headerRow && bodyRows.length > 1 && bodyRows[0].cells.length > 1 &&
+                        Array.from(bodyRows[0].cells).every(cell => cell.tagName === 'TH')) {
+                        headerRow = bodyRows.shift();
+                    }
+
+                    return {
+                        headers: headerRow ? cells(headerRow) : [],
+                        rows: bodyRows.map(cells).filter(row => row.some(cell => cell))
+                    };
+                });
+            })()
+            "#,
+        )
+        .await?
+        .into_value::<Vec<Table>>()?;
+
+    Ok(tables)
+}
+
+/// Cells from any table labeled by one of `labels` (regex fragments, matched
+/// case-insensitively against the start of the row label or header).
+pub fn table_values(tables: &[Table], labels: &[&str]) -> Result<Vec<String>> {
+    let label = Regex::new(&format!(r"(?i)^(?:{})\b", labels.join("|")))?;
+    Ok(tables
+        .iter()
+        .flat_map(|table| table.values_for(&label))
+        .map(String::from)
+        .collect())
+}
+
+/// Collects the page's label -> value pairs in one pass: `<dt>`/`<dd>`
+/// pairs, `<label>`s with their control or next sibling, and "Label: value"
+/// elements. Labels are lowercased with the trailing colon dropped; where a
+/// label repeats, the first value on the page is kept.
+pub async fn extract_facts(page: &Page) -> Result<HashMap<String, String>> {
+    let pairs = page
+        .evaluate(
+            r#"
+            (() => {
+                const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                const pairs = [];
+                const add = (label, value) => {
+                    label = clean(label).replace(/:$/, '').trim();
+                    value = clean(value);
+                    if (label && value && label.length <= 60 && value.length <= 300) {
+                        pairs.push([label, value]);
+                    }
+                };
+
+                // <dt>Label</dt><dd>Value</dd>, with any <dd> run belonging to the <dt> before it
+                for (const dt of document.querySelectorAll('dt')) {
+                    const values = [];
+                    let next = dt.nextElementSibling;
+                    while (next && next.tagName === 'DD') {
+                        values.push(clean(next.textContent));
+                        next = next.nextElementSibling;
+                    }
+                    add(dt.textContent, values.join(', '));
+                }
+
+                // <label> with the control it names, or the element right after it
+                for (const label of document.querySelectorAll('label')) {
+                    const control = label.control || label.nextElementSibling;
+                    if (control) {
+                        add(label.textContent, control.value !== undefined && control.value !== '' ? control.value : control.textContent);
+                    }
+                }
+
+                // Leaf elements written as "Label: value"
+                for (const el of document.querySelectorAll('li, p, span, div')) {
+                    if (el.children.length > 0) {
+                        continue;
+                    }
+                    const match = clean(el.textContent).match(/^([^:]{2,60}):\s*(.+)$/);
+                    if (match && !/https?$/i.test(match[1])) {
+                        add(match[1], match[2]);
+                    }
+                }
+
+                return pairs;
+            })()
+            "#,
+        )
+        .await?
+        .into_value::<Vec<(String, String)>>()?;
+
+    let mut facts = HashMap::new();
+    for (label, value) in pairs {
+        facts.entry(label.to_lowercase()).or_insert(value);
+    }
+    Ok(facts)
+}
+
+/// The value of the fact labeled by one of `labels` (regex fragments, as
+/// for `table_values`), preferring earlier labels.
+pub fn fact_value(facts: &HashMap<String, String>, labels: &[&str]) -> Result<Option<String>> {
+    for label in labels {
+        let label = Regex::new(&format!(r"(?i)^(?:{})$", label))?;
+        if let Some(value) = facts
+            .iter()
+            .filter(|(fact, _)| label.is_match(fact))
+            .min_by_key(|(fact, _)| fact.len())
+            .map(|(_, value)| value)
+        {
+            return Ok(Some(value.clone()));
+        }
+    }
+    Ok(None)
+}
+
+/// schema.org types describing the fund or its firm. Other entities on the
+/// page (`WebSite`, `BreadcrumbList`, ...) are ignored.
+const ORGANIZATION_TYPES: &[&str] = &[
+    "Organization",
+    "Corporation",
+    "InvestmentFund",
+    "FinancialService",
+    "FinancialProduct",
+    "LocalBusiness",
+];
+
+/// Fund fields read from the profile's schema.org structured data, which
+/// is authored by the site and so more reliable than rendered text.
+#[derive(Debug, Default)]
+pub struct StructuredData {
+    /// Field values keyed by column name, with the strategy that found them.
+    fields: BTreeMap<&'static str, (String, &'static str)>,
+}
+
+impl StructuredData {
+    /// The value for a column, if the structured data has one, and whether
+    /// it came from JSON-LD or microdata.
+    pub fn get(&self, field: &str) -> Option<(&str, &'static str)> {
+        self.fields.get(field).map(|(value, strategy)| (value.as_str(), *strategy))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Maps a schema.org organization onto fund fields, keeping fields an
+    /// earlier entity already filled.
+    fn add_entity(&mut self, entity: &Value, strategy: &'static str) {
+        let text = |key: &str| {
+            entity
+                .get(key)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(String::from)
+        };
+        let same_as: Vec<&str> = match entity.get("sameAs") {
+            Some(Value::String(url)) => vec![url.as_str()],
+            Some(Value::Array(urls)) => urls.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        let linkedin = same_as
+            .into_iter()
+            .find(|url| url.contains("linkedin.com/"))
+            .map(String::from);
+        // Often the listing's own URL rather than the fund's
+        let website = text("url").and_then(|url| normalize::website(&url));
+
+        for (field, value) in [
+            ("fund_name", text("name")),
+            ("fund_description", text("description")),
+            ("linkedin_url", linkedin),
+            ("website_url", website),
+        ] {
+            if let Some(value) = value {
+                self.fields.entry(field).or_insert((value, strategy));
+            }
+        }
+    }
+}
+
+/// Reads `<script type="application/ld+json">` blocks and schema.org
+/// microdata for the fund's organization. Blocks that don't parse are
+/// skipped; a page without any gives empty `StructuredData`.
+pub async fn extract_structured_data(page: &Page) -> Result<StructuredData> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Sources {
+        json_ld: Vec<String>,
+        microdata: Vec<Value>,
+    }
+
+    let sources = page
+        .evaluate(
+            r#"
+            (() => {
+                const jsonLd = Array.from(document.querySelectorAll('script[type="application/ld+json"]'))
+                    .map(script => script.textContent || '');
+
+                // Top-level microdata items, flattened into JSON-LD shaped objects
+                const microdata = [];
+                for (const item of document.querySelectorAll('[itemscope][itemtype*="schema.org"]:not([itemprop])')) {
+                    const entity = { '@type': (item.getAttribute('itemtype') || '').split('/').pop() };
+                    for (const prop of item.querySelectorAll('[itemprop]')) {
+                        if (prop.closest('[itemscope]') !== item && prop.parentElement.closest('[itemscope]') !== item) {
+                            continue;
+                        }
+                        const name = prop.getAttribute('itemprop');
+                        const value = prop.getAttribute('content') || prop.getAttribute('href') || (prop.textContent || '').trim();
+                        if (name === 'sameAs') {
+                            (entity.sameAs = entity.sameAs || []).push(value);
+                        } else if (!(name in entity)) {
+                            entity[name] = value;
+                        }
+                    }
+                    microdata.push(entity);
+                }
+
+                return { jsonLd, microdata };
+            })()
+            "#,
+        )
+        .await?
+        .into_value::<Sources>()?;
+
+    let mut data = StructuredData::default();
+    for block in &sources.json_ld {
+        match serde_json::from_str::<Value>(block) {
+            Ok(value) => {
+                for entity in linked_data_entities(&value).into_iter().filter(|entity| is_organization(entity)) {
+                    data.add_entity(entity, "json_ld");
+                }
+            }
+            Err(e) => debug!("Skipping unparseable JSON-LD block: {}", e),
+        }
+    }
+    for entity in sources.microdata.iter().filter(|entity| is_organization(entity)) {
+        data.add_entity(entity, "microdata");
+    }
+    Ok(data)
+}
+
+/// The entities in a JSON-LD document: the value itself, an array's items,
+/// or the items of an `@graph`.
+fn linked_data_entities(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().flat_map(linked_data_entities).collect(),
+        Value::Object(object) => match object.get("@graph") {
+            Some(graph) => linked_data_entities(graph),
+            None => vec![value],
+        },
+        _ => Vec::new(),
+    }
+}
+
+fn is_organization(entity: &Value) -> bool {
+    let is_known = |value: &Value| value.as_str().is_some_and(|kind| ORGANIZATION_TYPES.contains(&kind));
+    match entity.get("@type") {
+        Some(Value::Array(kinds)) => kinds.iter().any(is_known),
+        Some(kind) => is_known(kind),
+        None => false,
+    }
+}
+
+/// Smallest and largest figures taken for an unlabeled AUM, to skip things
+/// like years, ticket sizes and page counters.
+const PLAUSIBLE_AUM: (f64, f64) = (1e5, 1e13);
+
+/// A money figure found on the page with the text just before it.
+#[derive(Debug, Deserialize)]
+pub struct AmountCandidate {
+    pub value: String,
+    /// Up to 60 characters leading up to the figure, or the text of the
+    /// preceding label element when the figure stands alone (`<dd>€3B</dd>`).
+    pub context: String,
+}
+
+/// A money figure as written on a profile: a currency symbol or code, or a
+/// multiplier word (the same ones `normalize::parse_amount` expands), matched
+/// case-insensitively.
+const AMOUNT_PATTERN: &str = r"[€$£¥]\s*\d[\d,.]*(?:\s*(?:trillions?|billions?|millions?|thousands?|trn|tn|bln|bn|mln|mio|mn|mm|[tbmk])\b)?\+?(?:\s*(?:EUR|USD|GBP)\b)?|\d[\d,.]*\s*(?:trillions?|billions?|millions?|thousands?|trn|tn|bln|bn|mln|mio|mn|mm|[tbmk])\b\+?(?:\s*(?:EUR|USD|GBP)\b)?|\d[\d,.]*\s*(?:EUR|USD|GBP)\b";
+
+/// Collects every money figure on the page (a currency symbol or code, or a
+/// multiplier word such as B, bn or mln) in DOM order, with the label context
+/// around it.
+async fn extract_amount_candidates(page: &Page) -> Result<Vec<AmountCandidate>> {
+    let script = js_with_arg(
+        r#"
+            (() => {
+                const amount = new RegExp(__ARG__, 'gi');
+                const clean = (text) => (text || '').trim().replace(/\s+/g, ' ');
+                const seen = new Set();
+                const found = [];
+
+                const walker = document.createTreeWalker(document.body, NodeFilter.SHOW_TEXT);
+                let node;
+                while ((node = walker.nextNode())) {
+                    const text = node.textContent || '';
+                    for (const match of text.matchAll(amount)) {
+                        let context = clean(text.slice(Math.max(0, match.index - 60), match.index));
+                        if (!context) {
+                            // The label is a separate element, e.g. <dt>AUM</dt><dd>€3B</dd>
+                            const parent = node.parentElement;
+                            const label = parent && (parent.previousElementSibling ||
+                                (parent.parentElement && parent.parentElement.previousElementSibling));
+                            context = label ? clean(label.textContent).slice(-60) : '';
+                        }
+
+                        const value = clean(match[0]);
+                        const key = value + '|' + context;
+                        if (!seen.has(key)) {
+                            seen.add(key);
+                            found.push({ value, context });
+                        }
+                    }
+                }
+
+                return found;
+            })()
+            "#,
+        &AMOUNT_PATTERN,
+    )?;
+    let candidates = page.evaluate(script).await?.into_value::<Vec<AmountCandidate>>()?;
+
+    Ok(candidates)
+}
+
+/// Picks the figure whose context ends closest to one of `labels` (regex
+/// fragments), so `Valuation €2B ... AUM: €300M` yields the €300M even though
+/// it comes second. With no labeled figure and `fallback_to_largest`, takes
+/// the largest plausible figure. Returns the raw text, parsed amount and
+/// strategy.
+pub fn choose_amount(
+    candidates: &[AmountCandidate],
+    labels: &[&str],
+    fallback_to_largest: bool,
+) -> Result<Option<(String, f64, &'static str)>> {
+    let label = Regex::new(&format!(r"(?i)\b(?:{})\b", labels.join("|")))?;
+
+    let labeled = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let label_end = label.find_iter(&candidate.context).last()?.end();
+            let distance = candidate.context.len() - label_end;
+            let amount = normalize::parse_amount(&candidate.value)?;
+            Some((distance, candidate, amount))
+        })
+        // Stable on ties, so the first in DOM order wins
+        .min_by_key(|(distance, _, _)| *distance);
+    if let Some((_, candidate, amount)) = labeled {
+        return Ok(Some((candidate.value.clone(), amount, "labeled")));
+    }
+
+    if !fallback_to_largest {
+        return Ok(None);
+    }
+    let largest = candidates
+        .iter()
+        .filter_map(|candidate| Some((candidate, normalize::parse_amount(&candidate.value)?)))
+        .filter(|(_, amount)| (PLAUSIBLE_AUM.0..=PLAUSIBLE_AUM.1).contains(amount))
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+    Ok(largest.map(|(candidate, amount)| (candidate.value.clone(), amount, "largest")))
+}
+
+/// Substitutes `__ARG__` in an extraction script with a JSON-encoded value.
+pub fn js_with_arg(script: &str, arg: &impl Serialize) -> Result<String> {
+    Ok(script.replace("__ARG__", &serde_json::to_string(arg)?))
+}
+
+/// A value returned by an extraction script, tagged with the strategy that found it.
+#[derive(Debug, Deserialize)]
+pub struct Extracted {
+    pub value: String,
+    pub strategy: String,
+    /// Values for other fields found alongside this one, keyed by column name.
+    #[serde(default)]
+    pub related: BTreeMap<String, String>,
+    /// Text as found on the page before cleaning, when it differs from `value`.
+    #[serde(default)]
+    pub raw: Option<String>,
+}
+
+impl Extracted {
+    pub fn new(value: String, strategy: &str) -> Self {
+        Self {
+            value,
+            strategy: strategy.to_string(),
+            related: BTreeMap::new(),
+            raw: None,
+        }
+    }
+
+    /// Nothing found.
+    pub fn none() -> Self {
+        Self::new(String::new(), "none")
+    }
+
+    pub fn confidence(&self) -> f32 {
+        if self.value.is_empty() {
+            0.0
+        } else {
+            strategy_confidence(&self.strategy)
+        }
+    }
+}
+
+/// How much to trust a value given the strategy that produced it. Labeled or
+/// dedicated elements score high, broad page-wide heuristics score low.
+pub fn strategy_confidence(strategy: &str) -> f32 {
+    match strategy {
+        "json_ld" | "microdata" => 0.95,
+        "og_title" | "heading" | "name_class" | "labeled" | "profile_link" | "table" | "fact" | "tel_link" => 0.9,
+        "selector" | "section" => 0.8,
+        "chips" | "page_title" => 0.7,
+        "title_class" | "social_section" | "inline" => 0.6,
+        "icon_link" | "outbound_link" => 0.5,
+        "slug" | "portfolio_count" => 0.3,
+        _ => 0.4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(value: &str, context: &str) -> AmountCandidate {
+        AmountCandidate {
+            value: value.to_string(),
+            context: context.to_string(),
+        }
+    }
+
+    #[test]
+    fn labeled_aum_wins_over_earlier_figures() {
+        let candidates = [
+            candidate("€50M", "Portfolio company Acme was valued at"),
+            candidate("€120M", "Fund size:"),
+            candidate("€2B", "Assets under management:"),
+        ];
+        let chosen = choose_amount(&candidates, AUM_LABELS, true).unwrap();
+        assert_eq!(chosen, Some(("€2B".to_string(), 2_000_000_000.0, "labeled")));
+    }
+
+    #[test]
+    fn amount_pattern_keeps_multiplier_words() {
+        let pattern = regex::RegexBuilder::new(AMOUNT_PATTERN)
+            .case_insensitive(true)
+            .build()
+            .unwrap();
+        for (text, figure) in [
+            ("AUM of €2.5bn", "€2.5bn"),
+            ("Fund size $300mn.", "$300mn"),
+            ("Target €40 mln in 2024", "€40 mln"),
+            ("Manages 1.2 billion EUR", "1.2 billion EUR"),
+            ("Raised $3B+", "$3B+"),
+        ] {
+            assert_eq!(pattern.find(text).map(|m| m.as_str()), Some(figure), "{text}");
+        }
+    }
+
+    #[test]
+    fn aum_written_with_bn_suffix_is_expanded() {
+        let candidates = [candidate("€2.5bn", "AUM of")];
+        let chosen = choose_amount(&candidates, AUM_LABELS, true).unwrap();
+        assert_eq!(chosen, Some(("€2.5bn".to_string(), 2_500_000_000.0, "labeled")));
+    }
+
+    #[test]
+    fn bare_target_or_raising_in_running_text_is_not_a_fund_size() {
+        let candidates = [
+            candidate("€2M", "We target seed-stage startups raising up to"),
+            candidate("€500k", "Target ticket:"),
+        ];
+        assert_eq!(choose_amount(&candidates, FUND_SIZE_TEXT_LABELS, false).unwrap(), None);
+
+        let candidates = [candidate("€2M", "startups raising up to"), candidate("$40M", "Target fund size of")];
+        let chosen = choose_amount(&candidates, FUND_SIZE_TEXT_LABELS, false).unwrap();
+        assert_eq!(chosen, Some(("$40M".to_string(), 40_000_000.0, "labeled")));
+    }
+
+    #[test]
+    fn bare_target_label_is_trusted_as_a_fact() {
+        let facts = HashMap::from([
+            ("target".to_string(), "€60M".to_string()),
+            ("target markets".to_string(), "CEE".to_string()),
+        ]);
+        assert_eq!(fact_value(&facts, FUND_SIZE_LABELS).unwrap().as_deref(), Some("€60M"));
+    }
+
+    #[test]
+    fn closest_label_wins_when_several_figures_are_labeled() {
+        let candidates = [
+            candidate("$900M", "AUM grew last year; its portfolio was worth"),
+            candidate("$1.2B", "Today its AUM is"),
+        ];
+        let chosen = choose_amount(&candidates, AUM_LABELS, true).unwrap();
+        assert_eq!(chosen, Some(("$1.2B".to_string(), 1_200_000_000.0, "labeled")));
+    }
+
+    #[test]
+    fn falls_back_to_the_largest_plausible_figure_without_a_label() {
+        let candidates = [
+            candidate("€500M", "We manage"),
+            candidate("€30T", "Global markets are worth"),
+            candidate("€3B", "across funds totalling"),
+        ];
+        let chosen = choose_amount(&candidates, AUM_LABELS, true).unwrap();
+        assert_eq!(chosen, Some(("€3B".to_string(), 3_000_000_000.0, "largest")));
+        assert_eq!(choose_amount(&candidates, AUM_LABELS, false).unwrap(), None);
+    }
+
+    #[test]
+    fn reads_stated_deal_counts() {
+        assert_eq!(parse_deal_count("120+ investments").unwrap(), Some(120));
+        assert_eq!(parse_deal_count("We have closed 45 deals since 2015").unwrap(), Some(45));
+        assert_eq!(parse_deal_count("1,200 portfolio companies").unwrap(), Some(1200));
+        assert_eq!(parse_deal_count("Investments: 38").unwrap(), Some(38));
+    }
+
+    #[test]
+    fn no_deal_count_without_a_stated_number() {
+        assert_eq!(parse_deal_count("We invest in early-stage deals across Europe").unwrap(), None);
+        assert_eq!(parse_deal_count("").unwrap(), None);
+    }
+}
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use serde_json::json;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::models::Fund;
+use crate::output::write_atomically;
+
+/// Writes a co-investment graph: funds and portfolio companies are nodes,
+/// "invested in" relations are edges from fund to company. A `.graphml`
+/// path produces GraphML, anything else a JSON node-link document as read
+/// by networkx and d3.
+pub fn write_graph(filename: &str, funds: &[Fund]) -> Result<()> {
+    let graph = Graph::from_funds(funds);
+
+    let path = Path::new(filename);
+    let is_graphml = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("graphml"));
+    write_atomically(path, |tmp_path| {
+        let mut writer = BufWriter::new(File::create(tmp_path)?);
+        if is_graphml {
+            graph.write_graphml(&mut writer)?;
+        } else {
+            graph.write_node_link(&mut writer)?;
+        }
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+struct Node {
+    id: String,
+    kind: &'static str,
+    label: String,
+}
+
+struct Graph {
+    nodes: Vec<Node>,
+    edges: Vec<(String, String)>,
+}
+
+impl Graph {
+    fn from_funds(funds: &[Fund]) -> Self {
+        let mut nodes = Vec::new();
+        let mut companies = BTreeMap::new();
+        let mut edges = Vec::new();
+        let mut seen_edges = HashSet::new();
+
+        for fund in funds {
+            let fund_id = format!("fund:{}", fund.fund_url);
+            nodes.push(Node {
+                id: fund_id.clone(),
+                kind: "fund",
+                label: fund.fund_name.clone(),
+            });
+
+            for company in fund.fund_portfolio.split(';').map(str::trim).filter(|c| !c.is_empty()) {
+                let company_id = format!("company:{}", company);
+                companies.entry(company_id.clone()).or_insert_with(|| company.to_string());
+                let edge = (fund_id.clone(), company_id);
+                if seen_edges.insert(edge.clone()) {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        // Companies shared by several funds appear once, after all funds
+        nodes.extend(companies.into_iter().map(|(id, label)| Node {
+            id,
+            kind: "company",
+            label,
+        }));
+
+        Self { nodes, edges }
+    }
+
+    fn write_node_link(&self, writer: &mut impl Write) -> Result<()> {
+        let nodes: Vec<_> = self
+            .nodes
+            .iter()
+            .map(|node| json!({ "id": node.id, "type": node.kind, "label": node.label }))
+            .collect();
+        let links: Vec<_> = self
+            .edges
+            .iter()
+            .map(|(source, target)| json!({ "source": source, "target": target, "relation": "invested_in" }))
+            .collect();
+
+        let document = json!({
+            "directed": true,
+            "multigraph": false,
+            "graph": {},
+            "nodes": nodes,
+            "links": links,
+        });
+        serde_json::to_writer_pretty(writer, &document)?;
+        Ok(())
+    }
+
+    fn write_graphml(&self, writer: &mut impl Write) -> Result<()> {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(writer, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+        writeln!(writer, r#"  <key id="type" for="node" attr.name="type" attr.type="string"/>"#)?;
+        writeln!(writer, r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#)?;
+        writeln!(writer, r#"  <graph id="co-investment" edgedefault="directed">"#)?;
+
+        for node in &self.nodes {
+            writeln!(writer, r#"    <node id="{}">"#, xml_escape(&node.id))?;
+            writeln!(writer, r#"      <data key="type">{}</data>"#, node.kind)?;
+            writeln!(writer, r#"      <data key="label">{}</data>"#, xml_escape(&node.label))?;
+            writeln!(writer, "    </node>")?;
+        }
+        for (source, target) in &self.edges {
+            writeln!(
+                writer,
+                r#"    <edge source="{}" target="{}"/>"#,
+                xml_escape(source),
+                xml_escape(target)
+            )?;
+        }
+
+        writeln!(writer, "  </graph>")?;
+        writeln!(writer, "</graphml>")?;
+        Ok(())
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,87 @@
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::models::{Fund, JsonCase};
+use crate::output::{output_path, write_atomically, OutputFile};
+
+/// Writes all funds as a single pretty-printed JSON array, gzip-compressed
+/// with a `.gz` suffix if asked. Returns the path written.
+pub fn write_funds(filename: &str, funds: &[Fund], case: JsonCase, gzip: bool) -> Result<PathBuf> {
+    let values = funds
+        .iter()
+        .map(|fund| fund.to_json_value(case))
+        .collect::<Result<Vec<_>>>()?;
+
+    let path = output_path(Path::new(filename), gzip);
+    write_atomically(&path, |tmp_path| {
+        let mut writer = BufWriter::new(OutputFile::create(tmp_path, gzip)?);
+        serde_json::to_writer_pretty(&mut writer, &values)?;
+        writer.into_inner().map_err(|e| e.into_error())?.finish()
+    })?;
+    Ok(path)
+}
+
+/// Writes which strategy produced each field as JSON lines of
+/// `{"fund_url": ..., "provenance": {field: strategy}}`, one per fund.
+/// Funds reused from the conditional cache were not extracted this run
+/// and have no line.
+pub fn write_provenance(filename: &str, funds: &[Fund]) -> Result<()> {
+    write_atomically(Path::new(filename), |tmp_path| {
+        let mut writer = BufWriter::new(File::create(tmp_path)?);
+        for fund in funds.iter().filter(|fund| !fund.provenance.is_empty()) {
+            let line = serde_json::json!({
+                "fund_url": fund.fund_url,
+                "provenance": fund.provenance,
+            });
+            writeln!(writer, "{}", line)?;
+        }
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+/// Writes the raw, pre-cleaning text of one fund as `{field: raw_text}` to
+/// `<dir>/<index>_<slug>.json`, prefixed so files sort in discovery order.
+pub fn write_raw(dir: &str, index: usize, fund: &Fund) -> Result<()> {
+    let path = Path::new(dir).join(format!("{:05}_{}.json", index, raw_slug(&fund.fund_url)));
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, &fund.raw)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads back a `--emit-raw` directory as slug -> `{field: raw_text}`, for
+/// matching the raw text to funds by URL (see `raw_slug`).
+pub fn read_raw(dir: &str) -> Result<HashMap<String, BTreeMap<String, String>>> {
+    let mut raw = HashMap::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir))? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let Some((_, slug)) = stem.split_once('_') else {
+            continue;
+        };
+        let fields = serde_json::from_reader(BufReader::new(File::open(&path)?))
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        raw.insert(slug.to_string(), fields);
+    }
+    Ok(raw)
+}
+
+/// The last path segment of a fund URL, made safe for a file name.
+pub fn raw_slug(fund_url: &str) -> String {
+    fund_url
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
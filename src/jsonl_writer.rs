@@ -0,0 +1,116 @@
+use anyhow::Result;
+use std::io::Write;
+use std::path::PathBuf;
+use tracing::info;
+
+use crate::models::{Fund, JsonCase};
+use crate::output::{output_path, part_path, OutputFile};
+
+/// Streams funds as JSON Lines, one object per line, for tools that read
+/// newline-delimited JSON. Each line is written and flushed in one go, so a
+/// file cut short by a crash still ends on a complete line.
+pub struct JsonlExporter {
+    file: OutputFile,
+    path: PathBuf,
+    rows_per_file: Option<usize>,
+    rows_in_file: usize,
+    part: usize,
+    /// Every file written so far, in order.
+    paths: Vec<PathBuf>,
+    case: JsonCase,
+    gzip: bool,
+    fsync: bool,
+}
+
+impl JsonlExporter {
+    /// Creates the file, gzip-compressed with a `.gz` suffix if asked. With
+    /// `rows_per_file` set, output is split into `<name>_0001.jsonl`,
+    /// `<name>_0002.jsonl`, ... as the CSV is. With `fsync` each line is
+    /// synced to disk before `write_fund` returns.
+    pub fn new(
+        filename: &str,
+        case: JsonCase,
+        rows_per_file: Option<usize>,
+        gzip: bool,
+        fsync: bool,
+    ) -> Result<Self> {
+        let path = PathBuf::from(filename);
+        let part = 1;
+        let first_path = match rows_per_file {
+            Some(_) => part_path(&path, part),
+            None => path.clone(),
+        };
+        let first_path = output_path(&first_path, gzip);
+        let file = OutputFile::create(&first_path, gzip)?;
+        Ok(Self {
+            file,
+            path,
+            rows_per_file,
+            rows_in_file: 0,
+            part,
+            paths: vec![first_path],
+            case,
+            gzip,
+            fsync,
+        })
+    }
+
+    pub fn write_fund(&mut self, fund: &Fund) -> Result<()> {
+        if self.rows_per_file.is_some_and(|limit| self.rows_in_file >= limit) {
+            self.roll_over()?;
+        }
+
+        // Newlines inside values are escaped by serde_json, so a fund never spans lines
+        let mut line = serde_json::to_vec(&fund.to_json_value(self.case)?)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.flush()?;
+        if self.fsync {
+            self.file.sync_all()?;
+        }
+        self.rows_in_file += 1;
+        Ok(())
+    }
+
+    /// Finishes the output and returns the files written.
+    pub fn finalize(self) -> Result<Vec<PathBuf>> {
+        self.file.finish()?;
+        Ok(self.paths)
+    }
+
+    fn roll_over(&mut self) -> Result<()> {
+        self.part += 1;
+        let path = output_path(&part_path(&self.path, self.part), self.gzip);
+        info!("Rolling JSON Lines output over to {}", path.display());
+        let next = OutputFile::create(&path, self.gzip)?;
+        std::mem::replace(&mut self.file, next).finish()?;
+        self.paths.push(path);
+        self.rows_in_file = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolls_over_after_rows_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("funds.jsonl");
+        let mut exporter = JsonlExporter::new(path.to_str().unwrap(), JsonCase::default(), Some(2), false, false).unwrap();
+        for slug in ["acme-capital", "beta-ventures", "gamma-partners"] {
+            let mut fund = Fund::new();
+            fund.fund_url = format!("https://www.vestbee.com/lp/{}", slug);
+            exporter.write_fund(&fund).unwrap();
+        }
+
+        let paths = exporter.finalize().unwrap();
+        assert_eq!(paths, [dir.path().join("funds_0001.jsonl"), dir.path().join("funds_0002.jsonl")]);
+        let lines: Vec<usize> = paths
+            .iter()
+            .map(|path| std::fs::read_to_string(path).unwrap().lines().count())
+            .collect();
+        assert_eq!(lines, [2, 1]);
+    }
+}
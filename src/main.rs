@@ -1,62 +1,402 @@
+mod checkpoint;
+mod conditional;
+mod config;
 mod csv_writer;
+mod distribution;
+mod duplicates;
 mod excel_writer;
+mod export;
+mod extractors;
+mod graph_writer;
+mod json_writer;
+mod jsonl_writer;
+mod merge;
 mod models;
+mod normalize;
+mod output;
+mod pacing;
+mod preflight;
+mod preview;
+mod rate_limit;
+mod redact;
+mod reprocess;
+mod roster;
+mod s3_upload;
+mod schema;
 mod scraper;
+mod sitemap;
+mod sqlite_writer;
+mod validate;
 
-use anyhow::Result;
-use std::env;
-use tracing::{error, info};
-use tracing_subscriber;
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use futures::stream::{self, StreamExt};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::{error, info, warn};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::fmt;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::Registry;
 
+use crate::conditional::{ConditionalCache, Freshness, Validators};
+use crate::config::{Cli, Command, Config, LogFormat};
 use crate::csv_writer::CsvExporter;
-use crate::excel_writer::ExcelExporter;
-use crate::scraper::{scrape_with_retry, VestbeeScraper};
+use crate::export::{ExportQueue, Exporter};
+use crate::jsonl_writer::JsonlExporter;
+use crate::merge::{MergeTarget, CHANGES_LOG};
+use crate::models::{canonical_url, DiscoveredUrl, Fund};
+use crate::output::write_atomically;
+use crate::pacing::Pacer;
+use crate::rate_limit::AdaptiveRateLimiter;
+use crate::redact::Redacting;
+use crate::roster::PortfolioRoster;
+use crate::scraper::{scrape_isolated, Discovery, VestbeeScraper};
+use crate::sqlite_writer::SqliteExporter;
+
+/// Sidecar recording which strategy produced each field, for `--provenance`.
+const PROVENANCE_FILE: &str = "provenance.jsonl";
+
+/// Per-geography and per-sector fund counts, written after every run.
+const DISTRIBUTION_FILE: &str = "distribution.json";
+
+/// Discovered profile URLs, one per line, for `--dry-run`.
+const FUND_URLS_FILE: &str = "fund_urls.txt";
+
+/// Clusters found by `--duplicate-descriptions`.
+const DUPLICATES_FILE: &str = "duplicate_descriptions.json";
+
+/// Log target for the end-of-run summary, which is printed even in `--quiet` mode.
+const SUMMARY_TARGET: &str = "summary";
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::INFO)
-        .init();
+    // Mistakes on the command line get clap's own report, with suggestions
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::ValidateFile { path, options }) => {
+            let config = Config::from_options(options)?;
+            let _log_guard = init_logging(&config)?;
+            return validate::run(&path);
+        }
+        Some(Command::ReprocessFields { input, output, options }) => {
+            let config = Config::from_options(options)?;
+            let _log_guard = init_logging(&config)?;
+            return reprocess::run(&input, &output, config.emit_raw.as_deref());
+        }
+        Some(Command::Schema { path }) => return schema::run(path.as_deref()),
+        None => {}
+    }
+
+    let config = Config::from_options(cli.options)?;
+
+    // Held until exit so buffered file logs are flushed
+    let _log_guard = init_logging(&config)?;
+
+    // Counted from startup, so discovery is inside the window too
+    let deadline = config
+        .max_runtime_secs
+        .map(|secs| Instant::now() + Duration::from_secs(secs));
+
+    if config.preflight {
+        return preflight::run(&config).await;
+    }
 
     info!("Starting Vestbee LP List Scraper");
 
-    let args: Vec<String> = env::args().collect();
-    let headless = !args.contains(&"--headed".to_string());
+    std::fs::create_dir_all(&config.out_dir)
+        .with_context(|| format!("Failed to create output directory {}", config.out_dir))?;
+    let csv_path = config.dataset_path("csv");
+    let excel_path = config.dataset_path("xlsx");
+    let jsonl_path = config.dataset_path("jsonl");
+    let changes_log = config.output_path(CHANGES_LOG);
+    let absolute = |path: &str| std::path::absolute(path).map_or_else(|_| path.to_string(), |p| p.display().to_string());
+    if !config.dry_run {
+        info!("Writing CSV to {}", absolute(&csv_path));
+        info!("Writing Excel to {}", absolute(&excel_path));
+        if config.jsonl {
+            info!("Writing JSON Lines to {}", absolute(&jsonl_path));
+        }
+    }
     
-    if !headless {
+    if !config.headless {
         info!("Running in headed mode (browser visible)");
     }
 
-    let scraper = VestbeeScraper::new(headless).await?;
+    let scraper = VestbeeScraper::new(&config).await?;
     
-    info!("Fetching fund URLs from list page");
-    let fund_urls = scraper.get_fund_urls().await?;
+    let fund_urls = if let Some(path) = &config.urls_file {
+        let fund_urls = read_fund_urls(path)?;
+        info!("Read {} fund URLs from {}, skipping discovery", fund_urls.len(), path);
+        fund_urls
+    } else if let Some(sitemap_url) = &config.from_sitemap {
+        info!("Fetching fund URLs from sitemap {}", sitemap_url);
+        sitemap::fetch_fund_urls(sitemap_url, &config.profile_url_pattern).await?
+    } else {
+        // Each list is discovered in turn; funds on several are merged below
+        let list_urls = config.list_urls();
+        let mut fund_urls = Vec::new();
+        let mut empty_messages = Vec::new();
+        for list_url in &list_urls {
+            info!("Fetching fund URLs from list page {}", list_url);
+            match scraper.get_fund_urls(list_url).await? {
+                Discovery::Urls(urls) => fund_urls.extend(urls),
+                Discovery::ReportedEmpty(message) => {
+                    info!("{} reports zero results", list_url);
+                    empty_messages.push(message);
+                }
+            }
+        }
+        if empty_messages.len() == list_urls.len() {
+            warn!(
+                target: SUMMARY_TARGET,
+                "Site reports zero results (\"{}\"), nothing to scrape",
+                empty_messages.join("\", \"")
+            );
+            scraper.close().await?;
+            return Ok(());
+        }
+        fund_urls
+    };
     
+    let mut fund_urls = DiscoveredUrl::merge_sources(fund_urls);
+
+    // Only the scrape order changes: outputs are re-sorted by discovery index
+    if config.shuffle {
+        let seed = config.seed.unwrap_or_else(rand::random);
+        fund_urls.shuffle(&mut StdRng::seed_from_u64(seed));
+        info!("Shuffled scrape order with --seed {}", seed);
+    }
+
     if fund_urls.is_empty() {
-        error!("No fund URLs found. The page structure may have changed.");
+        error!("No fund URLs found and the site shows no empty-state message. The page structure may have changed.");
         return Ok(());
     }
     
     info!("Found {} funds to scrape", fund_urls.len());
 
-    let mut csv_writer = CsvExporter::new("data/vestbee_funds.csv")?;
-    csv_writer.write_header()?;
-    
+    if config.dry_run {
+        let urls_path = config.output_path(FUND_URLS_FILE);
+        write_fund_urls(&urls_path, &fund_urls)?;
+        info!(
+            target: SUMMARY_TARGET,
+            "Dry run: discovered {} funds, listed in {}. Nothing was scraped",
+            fund_urls.len(),
+            urls_path
+        );
+        scraper.close().await?;
+        return Ok(());
+    }
+
+    let mut roster = match &config.portfolio_roster {
+        Some(path) => {
+            let roster = PortfolioRoster::load(path)?;
+            info!("Loaded {} canonical portfolio names from {}", roster.len(), path);
+            Some(roster)
+        }
+        None => None,
+    };
+
+    if let Some(raw_dir) = &config.emit_raw {
+        std::fs::create_dir_all(raw_dir)?;
+    }
+
+    let mut merge_target = match &config.merge_into {
+        Some(path) => {
+            let target = MergeTarget::load(path, &changes_log)?;
+            info!("Merging into {} ({} funds)", path, target.len());
+            Some(target)
+        }
+        None => None,
+    };
+
+    let mut conditional_cache = match &config.conditional_cache {
+        Some(path) => {
+            let cache = ConditionalCache::load(path)?;
+            info!("Loaded validators for {} profiles from {}", cache.len(), path);
+            Some(cache)
+        }
+        None => None,
+    };
+
     let mut all_funds = Vec::new();
+    let csv_writer = if config.resume {
+        let (csv_writer, resumed) = CsvExporter::open_append(&csv_path, &config.columns, config.fsync)?;
+        let positions: HashMap<String, usize> = fund_urls
+            .iter()
+            .map(|discovered| (canonical_url(&discovered.url), discovered.index))
+            .collect();
+        let done: HashSet<String> = resumed.iter().map(|fund| canonical_url(&fund.fund_url)).collect();
+        fund_urls.retain(|discovered| !done.contains(&canonical_url(&discovered.url)));
+        info!(
+            "Resuming {}: {} funds already scraped, {} left",
+            csv_path,
+            resumed.len(),
+            fund_urls.len()
+        );
+
+        // Kept for the batch outputs, in discovery order when still listed
+        all_funds.extend(resumed.into_iter().map(|fund| {
+            let index = positions.get(&canonical_url(&fund.fund_url)).copied().unwrap_or(usize::MAX);
+            (index, fund)
+        }));
+        csv_writer
+    } else {
+        let mut csv_writer = CsvExporter::new(&csv_path, &config.columns, config.rows_per_file, config.gzip, config.fsync)?;
+        csv_writer.write_header()?;
+        csv_writer
+    };
+    let resumed_count = all_funds.len();
+
+    if let Some(limit) = config.limit.filter(|limit| *limit < fund_urls.len()) {
+        info!("Limiting to {} of {} funds (--limit)", limit, fund_urls.len());
+        fund_urls.truncate(limit);
+    }
+    let mut exporters: Vec<Box<dyn Exporter>> = vec![Box::new(csv_writer.with_summary(config.csv_summary))];
+    if config.jsonl {
+        exporters.push(Box::new(JsonlExporter::new(&jsonl_path, config.json_case, config.rows_per_file, config.gzip, config.fsync)?));
+    }
+    if let Some(path) = &config.sqlite {
+        exporters.push(Box::new(SqliteExporter::new(path)?));
+        info!("Upserting funds into {}", path);
+    }
+    let export_queue = ExportQueue::spawn(exporters, config.writer_concurrency);
+    
+    let rate_limiter = config.max_rps.map(|rps| Mutex::new(AdaptiveRateLimiter::new(rps)));
+    let pacer = if rate_limiter.is_some() {
+        Pacer::rate_limited()
+    } else {
+        Pacer::new(Duration::from_millis(config.delay_ms))
+    };
 
     let mut successful_count = 0;
     let mut failed_count = 0;
+    let mut empty_name_count = 0;
+    let mut unchanged_count = 0;
+    let mut rejected_count = 0;
+    let mut duplicate_count = 0;
+    let mut skipped_count = 0;
+    // Discovery merges listings of the same profile, but a fund can still
+    // come back under a URL that differs only in case, query or trailing slash
+    let mut written_urls: HashSet<String> = all_funds.iter().map(|(_, fund)| canonical_url(&fund.fund_url)).collect();
+    let mut preview_printed = false;
+    let mut retry_budget_exceeded = false;
+    // Recorded once scraping is done, as the workers still read the cache
+    let mut validated = Vec::new();
+
+    // Stop starting funds on Ctrl-C so the outputs are still finished properly
+    let stopping = Arc::new(AtomicBool::new(false));
+    {
+        let stopping = stopping.clone();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                warn!("Interrupted, finishing the funds in progress and writing outputs");
+                stopping.store(true, Ordering::SeqCst);
+            }
+        });
+    }
+    let time_truncated = AtomicBool::new(false);
+
+    if config.concurrency > 1 {
+        info!("Scraping up to {} funds at once", config.concurrency);
+    }
+
+    let fetcher = FundFetcher {
+        scraper: &scraper,
+        conditional_cache: conditional_cache.as_ref(),
+        rate_limiter: rate_limiter.as_ref(),
+        pacer,
+        deadline,
+        total: fund_urls.len(),
+        stopping: &stopping,
+        time_truncated: &time_truncated,
+    };
+    let mut fetches = stream::iter(fund_urls.iter().enumerate())
+        .map(|(idx, discovered)| fetcher.fetch(idx, discovered))
+        .buffer_unordered(config.concurrency);
+
+    // Workers only scrape; everything that touches the outputs happens here,
+    // one fund at a time in the order they finish
+    while let Some(fetched) = fetches.next().await {
+        let Some(Fetched {
+            discovered,
+            result,
+            reused,
+            validators,
+        }) = fetched?
+        else {
+            skipped_count += 1;
+            continue;
+        };
+        let url = &discovered.url;
+        if reused {
+            unchanged_count += 1;
+        }
+
+        match result {
+            Ok(mut fund) => {
+                fund.source_list = discovered.source_list.clone();
+
+                if let Some(validators) = validators {
+                    validated.push((url.clone(), validators, fund.clone()));
+                }
+
+                if let Some(raw_dir) = config.emit_raw.as_ref().filter(|_| !reused) {
+                    json_writer::write_raw(raw_dir, discovered.index, &fund)?;
+                }
+
+                if let Some(roster) = roster.as_mut() {
+                    fund.fund_portfolio = roster.canonicalize_portfolio(&fund.fund_portfolio);
+                }
+
+                // Strict mode rejects incomplete funds outright; they are only logged
+                let missing: Vec<&str> = config
+                    .required_fields
+                    .iter()
+                    .copied()
+                    .filter(|field| config.strict && fund.get_field(field).unwrap_or_default().is_empty())
+                    .collect();
+                let rejected = !missing.is_empty();
+                let canonical = canonical_url(&fund.fund_url);
+                let duplicate = written_urls.contains(&canonical);
+
+                // Only funds that are written out below belong in the dataset
+                let kept = !rejected && !duplicate && (!fund.fund_name.is_empty() || config.include_empty);
+                if let Some(target) = merge_target.as_mut().filter(|_| kept) {
+                    target.merge(&fund)?;
+                }
+                if kept {
+                    written_urls.insert(canonical);
+                }
 
-    for (idx, url) in fund_urls.iter().enumerate() {
-        info!("[{}/{}] Scraping: {}", idx + 1, fund_urls.len(), url);
-        
-        match scrape_with_retry(&scraper, url, 3).await {
-            Ok(fund) => {
-                if !fund.fund_name.is_empty() {
-                    csv_writer.write_fund(&fund)?;
+                if rejected {
+                    warn!("Rejecting {}: missing required {}", url, missing.join(", "));
+                    rejected_count += 1;
+                } else if duplicate {
+                    warn!("Skipping {}: the same fund was already written", url);
+                    duplicate_count += 1;
+                } else if !fund.fund_name.is_empty() {
+                    export_queue.send(fund.clone()).await?;
                     info!("Successfully scraped: {}", fund.fund_name);
-                    all_funds.push(fund);
+                    all_funds.push((discovered.index, fund));
                     successful_count += 1;
+                } else if config.include_empty {
+                    warn!("Scraped fund but name was empty for URL: {}, writing it for review", url);
+                    export_queue.send(fund.clone()).await?;
+                    all_funds.push((discovered.index, fund));
+                    empty_name_count += 1;
                 } else {
                     failed_count += 1;
                     error!("Scraped fund but name was empty for URL: {}", url);
@@ -67,24 +407,424 @@ async fn main() -> Result<()> {
                 error!("Failed to scrape {}: {}", url, e);
             }
         }
-        
-        if idx < fund_urls.len() - 1 {
-            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+        if let Some(preview) = config.preview.filter(|n| !preview_printed && all_funds.len() >= *n) {
+            preview::print(&all_funds.iter().take(preview).map(|(_, fund)| fund).collect::<Vec<_>>())?;
+            preview_printed = true;
+        }
+
+        // So many retries means the site is failing broadly, not one page
+        if let Some(budget) = config
+            .global_retry_budget
+            .filter(|budget| !retry_budget_exceeded && scraper.retry_count() > *budget)
+        {
+            error!(
+                "Retry budget exceeded ({} retries, budget {}), not starting the remaining funds",
+                scraper.retry_count(),
+                budget
+            );
+            retry_budget_exceeded = true;
+            stopping.store(true, Ordering::SeqCst);
+        }
+    }
+    drop(fetches);
+
+    if skipped_count > 0 && !time_truncated.load(Ordering::SeqCst) {
+        warn!("Stopped early, {} of {} funds were not scraped", skipped_count, fund_urls.len());
+    }
+
+    if let Some(cache) = conditional_cache.as_mut() {
+        for (url, validators, fund) in validated {
+            cache.record(&url, validators, &fund);
         }
     }
 
-    csv_writer.finalize()?;
+    // Runs that ended before reaching the preview size still show what they got
+    if config.preview.is_some() && !preview_printed && !all_funds.is_empty() {
+        preview::print(&all_funds.iter().map(|(_, fund)| fund).collect::<Vec<_>>())?;
+    }
+
+    let mut output_paths = export_queue.finish().await?;
+
+    if let Some(cache) = &conditional_cache {
+        cache.save()?;
+    }
+
+    if let Some(target) = &merge_target {
+        target.save()?;
+    }
+
+    // Restore discovery order so batch outputs mirror the site
+    all_funds.sort_by_key(|(index, _)| *index);
+    let all_funds: Vec<Fund> = all_funds.into_iter().map(|(_, fund)| fund).collect();
     
-    // Write all funds to Excel
-    let mut excel_writer = ExcelExporter::new()?;
-    excel_writer.write_funds(&all_funds)?;
-    excel_writer.save("data/vestbee_funds.xlsx")?;
+    // Write all funds to Excel, split like the CSV to bound memory
+    let excel_paths = excel_writer::save_workbooks(
+        &excel_path,
+        &config.columns,
+        &all_funds,
+        config.rows_per_file,
+    )?;
+    if excel_paths.len() > 1 {
+        info!("Wrote {} funds across {} Excel files", all_funds.len(), excel_paths.len());
+    }
+    output_paths.extend(excel_paths);
+
+    if let Some(json_path) = &config.json_path {
+        let path = json_writer::write_funds(json_path, &all_funds, config.json_case, config.gzip)?;
+        info!("Wrote {} funds to {}", all_funds.len(), path.display());
+        output_paths.push(path);
+    }
+
+    if config.provenance {
+        let provenance_path = config.output_path(PROVENANCE_FILE);
+        json_writer::write_provenance(&provenance_path, &all_funds)?;
+        info!("Wrote field provenance to {}", provenance_path);
+        output_paths.push(provenance_path.into());
+    }
+
+    if let Some(graph_path) = &config.graph_path {
+        graph_writer::write_graph(graph_path, &all_funds)?;
+        info!("Wrote co-investment graph to {}", graph_path);
+    }
+
+    let scraper_retries = scraper.retry_count();
     scraper.close().await?;
 
+    distribution::write_distribution(&config.output_path(DISTRIBUTION_FILE), &all_funds)?;
+
+    if config.duplicate_descriptions {
+        let clusters = duplicates::find_clusters(&all_funds);
+        let duplicates_path = config.output_path(DUPLICATES_FILE);
+        duplicates::write_clusters(&duplicates_path, &clusters)?;
+        if !clusters.is_empty() {
+            info!(
+                target: SUMMARY_TARGET,
+                "{} groups of funds share near-identical descriptions ({} funds), see {}",
+                clusters.len(),
+                clusters.iter().map(|cluster| cluster.funds.len()).sum::<usize>(),
+                duplicates_path
+            );
+        }
+    }
+
+    if let Some(bucket) = &config.s3_bucket {
+        s3_upload::upload(bucket, &config.s3_prefix, &output_paths).await?;
+        info!(target: SUMMARY_TARGET, "Uploaded {} output files to s3://{}", output_paths.len(), bucket);
+    }
+
+    if let Some(roster) = &roster {
+        if !roster.unmatched().is_empty() {
+            info!(
+                target: SUMMARY_TARGET,
+                "{} portfolio companies not in the roster: {}",
+                roster.unmatched().len(),
+                roster.unmatched().iter().cloned().collect::<Vec<_>>().join("; ")
+            );
+        }
+    }
+
+    if let Some(target) = &merge_target {
+        info!(
+            target: SUMMARY_TARGET,
+            "Merged into {}: {} field changes logged to {}",
+            config.merge_into.as_deref().unwrap_or_default(),
+            target.change_count(),
+            changes_log
+        );
+    }
+
+    if unchanged_count > 0 {
+        info!(
+            target: SUMMARY_TARGET,
+            "Reused {} unchanged funds from the conditional cache",
+            unchanged_count
+        );
+    }
+
+    if rejected_count > 0 {
+        info!(
+            target: SUMMARY_TARGET,
+            "Rejected {} funds missing required fields ({})",
+            rejected_count,
+            config.required_fields.join(", ")
+        );
+    }
+
+    if duplicate_count > 0 {
+        info!(
+            target: SUMMARY_TARGET,
+            "Skipped {} duplicate funds whose URL matched one already written",
+            duplicate_count
+        );
+    }
+
+    if resumed_count > 0 {
+        info!(
+            target: SUMMARY_TARGET,
+            "Resumed {} with {} funds scraped by the earlier run",
+            csv_path,
+            resumed_count
+        );
+    }
+
+    if empty_name_count > 0 {
+        info!(
+            target: SUMMARY_TARGET,
+            "Wrote {} funds without a name for review (name_source = none)",
+            empty_name_count
+        );
+    }
+
     info!(
-        "Scraping complete! Successfully scraped {} funds, {} failed. Data saved to data/vestbee_funds.csv and data/vestbee_funds.xlsx",
-        successful_count, failed_count
+        target: SUMMARY_TARGET,
+        "Scraping complete! Successfully scraped {} funds, {} failed. Data saved to {} and {}",
+        successful_count, failed_count, csv_path, excel_path
     );
 
+    if config.url.len() > 1 {
+        let discovered = source_counts(fund_urls.iter().map(|discovered| discovered.source_list.as_str()));
+        let scraped = source_counts(all_funds.iter().map(|fund| fund.source_list.as_str()));
+        for (source, count) in &discovered {
+            info!(
+                target: SUMMARY_TARGET,
+                "{}: {} funds found, {} written",
+                source,
+                count,
+                scraped.get(source).copied().unwrap_or_default()
+            );
+        }
+    }
+
+    if time_truncated.load(Ordering::SeqCst) {
+        warn!(
+            target: SUMMARY_TARGET,
+            "Run truncated at --max-runtime-secs {}: {} of {} funds were not scraped, outputs are partial",
+            config.max_runtime_secs.unwrap_or_default(),
+            skipped_count,
+            fund_urls.len()
+        );
+    }
+
+    if let Some(max_rejected) = config.max_rejected.filter(|max| rejected_count > *max) {
+        bail!(
+            "{} funds were rejected by --strict, more than --max-rejected {}",
+            rejected_count,
+            max_rejected
+        );
+    }
+
+    if retry_budget_exceeded {
+        bail!(
+            "Aborting: site appears to be failing ({} retries exceeded --global-retry-budget). Outputs hold the {} funds scraped before stopping",
+            scraper_retries,
+            successful_count
+        );
+    }
+
     Ok(())
 }
+
+/// In `--quiet` mode only warnings, errors and the final summary are logged
+/// to the terminal. The `--log-file` archive always gets everything at info.
+/// With `--redact-pii` both mask emails and phone numbers.
+fn init_logging(config: &Config) -> Result<Option<WorkerGuard>> {
+    let level = if config.quiet { LevelFilter::WARN } else { LevelFilter::INFO };
+    // `--verbose` only opens up our own debug messages, not the browser's
+    let own_level = if config.verbose { LevelFilter::DEBUG } else { level };
+    let filter = Targets::new()
+        .with_default(level)
+        .with_target(env!("CARGO_CRATE_NAME"), own_level)
+        .with_target(SUMMARY_TARGET, LevelFilter::INFO);
+
+    let (file_layer, guard) = match &config.log_file {
+        Some(log_file) => {
+            let path = Path::new(log_file);
+            let file_name = path
+                .file_name()
+                .with_context(|| format!("--log-file {} has no file name", log_file))?;
+            let dir = path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+
+            let (writer, guard) = tracing_appender::non_blocking(tracing_appender::rolling::daily(dir, file_name));
+            let writer = if config.redact_pii {
+                BoxMakeWriter::new(Redacting::new(writer))
+            } else {
+                BoxMakeWriter::new(writer)
+            };
+            let layer = fmt::layer().with_writer(writer).with_ansi(false);
+            let layer: Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync> = match config.log_format {
+                LogFormat::Text => layer.boxed(),
+                LogFormat::Json => layer.json().boxed(),
+            };
+            let file_filter = Targets::new()
+                .with_default(LevelFilter::INFO)
+                .with_target(env!("CARGO_CRATE_NAME"), own_level.max(LevelFilter::INFO));
+            (Some(layer.with_filter(file_filter)), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    let terminal_writer = if config.redact_pii {
+        BoxMakeWriter::new(Redacting::new(std::io::stdout))
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
+
+    tracing_subscriber::registry()
+        .with(file_layer)
+        .with(fmt::layer().with_writer(terminal_writer).with_filter(filter))
+        .init();
+
+    Ok(guard)
+}
+
+/// Funds per source list. A fund found on several lists counts for each.
+fn source_counts<'a>(source_lists: impl Iterator<Item = &'a str>) -> BTreeMap<&'a str, usize> {
+    let mut counts = BTreeMap::new();
+    for source in source_lists.flat_map(|sources| sources.split(';')).map(str::trim) {
+        *counts.entry(source).or_default() += 1;
+    }
+    counts
+}
+
+/// One fund as a worker left it, for the main loop to write out.
+struct Fetched<'a> {
+    discovered: &'a DiscoveredUrl,
+    result: Result<Fund>,
+    /// Taken unchanged from the conditional cache without opening the page.
+    reused: bool,
+    validators: Option<Validators>,
+}
+
+/// The per-fund work the `--concurrency` workers run side by side: the
+/// conditional check, rate limiting and the scrape, paced per worker.
+struct FundFetcher<'a> {
+    scraper: &'a VestbeeScraper,
+    conditional_cache: Option<&'a ConditionalCache>,
+    rate_limiter: Option<&'a Mutex<AdaptiveRateLimiter>>,
+    pacer: Pacer,
+    deadline: Option<Instant>,
+    total: usize,
+    /// Set on Ctrl-C or once the retry budget is spent; funds not yet
+    /// started are skipped, those in progress finish.
+    stopping: &'a AtomicBool,
+    time_truncated: &'a AtomicBool,
+}
+
+impl<'a> FundFetcher<'a> {
+    /// Scrapes the fund at `idx`, or returns `None` when the run stopped or
+    /// ran out of time before it was done.
+    async fn fetch(&self, idx: usize, discovered: &'a DiscoveredUrl) -> Result<Option<Fetched<'a>>> {
+        if self.stopping.load(Ordering::SeqCst) {
+            return Ok(None);
+        }
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            if !self.time_truncated.swap(true, Ordering::SeqCst) {
+                warn!("Reached --max-runtime-secs, not starting the remaining funds");
+            }
+            return Ok(None);
+        }
+
+        let url = &discovered.url;
+        info!("[{}/{}] Scraping: {}", idx + 1, self.total, url);
+
+        if !self.scraper.is_healthy() {
+            warn!("Browser is unhealthy, restarting before next fund");
+            self.scraper.relaunch().await?;
+        }
+
+        let freshness = match self.conditional_cache {
+            Some(cache) => match cache.check(url).await {
+                Ok(freshness) => Some(freshness),
+                Err(e) => {
+                    warn!("Conditional request for {} failed, scraping anyway: {}", url, e);
+                    None
+                }
+            },
+            None => None,
+        };
+        let validators = match freshness {
+            Some(Freshness::Unchanged(fund)) => {
+                // Never touched the browser, so there is nothing to pace
+                info!("Unchanged since last run, reusing: {}", url);
+                return Ok(Some(Fetched {
+                    discovered,
+                    result: Ok(*fund),
+                    reused: true,
+                    validators: None,
+                }));
+            }
+            Some(Freshness::Changed(validators)) => Some(validators),
+            None => None,
+        };
+
+        if let Some(rate_limiter) = self.rate_limiter {
+            rate_limiter.lock().await.acquire().await;
+        }
+
+        let scrape = scrape_isolated(self.scraper, url, 3);
+        let result = match self.deadline {
+            Some(deadline) => match tokio::time::timeout_at(deadline, scrape).await {
+                Ok(result) => result,
+                Err(_) => {
+                    warn!("Reached --max-runtime-secs while scraping {}, dropping it", url);
+                    self.time_truncated.store(true, Ordering::SeqCst);
+                    return Ok(None);
+                }
+            },
+            None => scrape.await,
+        };
+        if let Some(rate_limiter) = self.rate_limiter {
+            let mut rate_limiter = rate_limiter.lock().await;
+            if result.is_ok() {
+                rate_limiter.on_success();
+            } else {
+                rate_limiter.on_failure();
+            }
+        }
+
+        if !self.stopping.load(Ordering::SeqCst) {
+            self.pacer.pause_after(idx, self.total).await;
+        }
+
+        Ok(Some(Fetched {
+            discovered,
+            result,
+            reused: false,
+            validators,
+        }))
+    }
+}
+
+/// Writes one URL per line, in the order they would be scraped.
+fn write_fund_urls(path: &str, fund_urls: &[DiscoveredUrl]) -> Result<()> {
+    write_atomically(Path::new(path), |tmp_path| {
+        let mut writer = BufWriter::new(File::create(tmp_path)?);
+        for discovered in fund_urls {
+            writeln!(writer, "{}", discovered.url)?;
+        }
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+/// Reads the profile URLs in `path`, one per line, as written by `--dry-run`.
+/// Blank lines and `#` comments are skipped.
+fn read_fund_urls(path: &str) -> Result<Vec<DiscoveredUrl>> {
+    let contents = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    let mut urls = Vec::new();
+    for (line_number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if !reqwest::Url::parse(line).is_ok_and(|parsed| matches!(parsed.scheme(), "http" | "https")) {
+            bail!("{} line {}: expected an http(s) URL, got {:?}", path, line_number + 1, line);
+        }
+        urls.push(line.to_string());
+    }
+    Ok(DiscoveredUrl::index_all(urls, path))
+}
@@ -0,0 +1,190 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset, Utc};
+use csv::Writer;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::csv_writer::{header_for, read_funds, CsvRows};
+use crate::models::{canonical_url, Fund, FIELD_NAMES};
+use crate::output::write_atomically;
+
+/// Audit log of field-level changes made by `--merge-into`, appended to
+/// across runs, in the output directory.
+pub const CHANGES_LOG: &str = "changes.jsonl";
+
+/// Columns that change on every scrape and so aren't data changes.
+const UNTRACKED_COLUMNS: &[&str] = &["scraped_at"];
+
+/// One field that differed between the existing dataset and this run.
+#[derive(Serialize)]
+struct Change<'a> {
+    fund_url: &'a str,
+    field: &'a str,
+    /// `None` when the fund wasn't in the dataset before.
+    old: Option<&'a str>,
+    new: &'a str,
+    scraped_at: &'a str,
+}
+
+/// An existing CSV dataset that this run's funds are merged into: known
+/// funds are updated in place, new ones appended, and every changed field
+/// logged to `CHANGES_LOG`. Funds not scraped this run are kept as they were.
+pub struct MergeTarget {
+    path: PathBuf,
+    columns: Vec<&'static str>,
+    funds: Vec<Fund>,
+    /// Canonical fund URL -> position in `funds`.
+    index: HashMap<String, usize>,
+    changes: BufWriter<File>,
+    change_count: usize,
+}
+
+impl MergeTarget {
+    /// Reads the dataset. Its known columns decide which fields are compared
+    /// and written back.
+    pub fn load(path: &str, changes_log: &str) -> Result<Self> {
+        let CsvRows { columns, rows } =
+            read_funds(path).with_context(|| format!("Failed to load merge target {}", path))?;
+
+        let mut funds = Vec::new();
+        let mut index = HashMap::new();
+        for (line, fund) in rows {
+            let fund = fund.with_context(|| format!("Line {} of merge target {}", line, path))?;
+            // Without a URL there is nothing to match scraped funds against
+            if fund.fund_url.is_empty() {
+                continue;
+            }
+            index.insert(canonical_url(&fund.fund_url), funds.len());
+            funds.push(fund);
+        }
+
+        if let Some(parent) = Path::new(changes_log).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let changes = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(changes_log)
+            .with_context(|| format!("Failed to open change log {}", changes_log))?;
+
+        Ok(Self {
+            path: PathBuf::from(path),
+            columns,
+            funds,
+            index,
+            changes: BufWriter::new(changes),
+            change_count: 0,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.funds.len()
+    }
+
+    /// Number of field changes logged so far.
+    pub fn change_count(&self) -> usize {
+        self.change_count
+    }
+
+    /// Merges one scraped fund, logging each tracked field that changes.
+    /// Where both have a value for a field, the one with the newer
+    /// `scraped_at` wins; an empty value never replaces a populated one.
+    pub fn merge(&mut self, fund: &Fund) -> Result<()> {
+        let key = canonical_url(&fund.fund_url);
+        let existing = self.index.get(&key).map(|&position| &self.funds[position]);
+        let scraped_at = if fund.scraped_at.is_empty() {
+            Utc::now().to_rfc3339()
+        } else {
+            fund.scraped_at.clone()
+        };
+        let merged = match existing {
+            Some(existing) => resolve(existing, fund)?,
+            None => fund.clone(),
+        };
+
+        for column in &self.columns {
+            // Confidence scores are derived from the data, not data themselves
+            if column.ends_with("_confidence") || UNTRACKED_COLUMNS.contains(column) {
+                continue;
+            }
+            let new = merged.get_field(column).unwrap_or_default();
+            let old = existing.map(|existing| existing.get_field(column).unwrap_or_default());
+            if old.as_deref() == Some(new.as_ref()) || (old.is_none() && new.is_empty()) {
+                continue;
+            }
+
+            let line = serde_json::to_string(&Change {
+                fund_url: &fund.fund_url,
+                field: column,
+                old: old.as_deref(),
+                new: &new,
+                scraped_at: &scraped_at,
+            })?;
+            writeln!(self.changes, "{}", line)?;
+            self.change_count += 1;
+        }
+        // Flushed per fund so the log never trails the data it justifies
+        self.changes.flush()?;
+
+        match self.index.get(&key) {
+            Some(&position) => self.funds[position] = merged,
+            None => {
+                self.index.insert(key, self.funds.len());
+                self.funds.push(merged);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the merged dataset back over the original file.
+    pub fn save(&self) -> Result<()> {
+        write_atomically(&self.path, |tmp_path| {
+            let mut writer = Writer::from_path(tmp_path)?;
+            writer.write_record(self.columns.iter().map(|column| header_for(column)))?;
+            for fund in &self.funds {
+                let values: Vec<_> = self
+                    .columns
+                    .iter()
+                    .map(|column| fund.get_field(column).unwrap_or_default())
+                    .collect();
+                writer.write_record(values.iter().map(|value| value.as_bytes()))?;
+            }
+            writer.flush()?;
+            Ok(())
+        })
+    }
+}
+
+/// `existing` and `scraped` combined field by field: the newer one's
+/// non-empty values, falling back to the older one's where it has none.
+/// A fund without a readable `scraped_at` counts as the older one.
+fn resolve(existing: &Fund, scraped: &Fund) -> Result<Fund> {
+    let scraped_is_newer = match (scraped_time(existing), scraped_time(scraped)) {
+        (Some(existing_at), Some(scraped_at)) => scraped_at >= existing_at,
+        (None, _) => true,
+        (Some(_), None) => false,
+    };
+    let (newer, older) = if scraped_is_newer { (scraped, existing) } else { (existing, scraped) };
+
+    let mut merged = newer.clone();
+    for field in FIELD_NAMES {
+        let value = newer.get_field(field).unwrap_or_default();
+        if value.is_empty() {
+            let fallback = older.get_field(field).unwrap_or_default();
+            if !fallback.is_empty() {
+                merged.set_field(field, fallback.into_owned())?;
+                if let Some(confidence) = older.confidence.get(field) {
+                    merged.confidence.set(field, confidence);
+                }
+            }
+        }
+    }
+    Ok(merged)
+}
+
+fn scraped_time(fund: &Fund) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(&fund.scraped_at).ok()
+}
@@ -1,32 +1,835 @@
+use anyhow::{bail, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::normalize;
+
+/// Output columns in their default order.
+pub const FIELD_NAMES: &[&str] = &[
+    "fund_name",
+    "name_source",
+    "fund_url",
+    "source_list",
+    "aum",
+    "aum_raw",
+    "aum_currency",
+    "fund_size",
+    "fund_size_currency",
+    "ticket_size",
+    "linkedin_url",
+    "website_url",
+    "contact_phone",
+    "investment_geographies",
+    "investment_stages",
+    "headquarters",
+    "status",
+    "close_status",
+    "close_date",
+    "management_fee",
+    "carry",
+    "fund_description",
+    "fund_portfolio",
+    "deal_count",
+    "tags",
+    "scraped_at",
+];
+
+/// A profile URL tagged with its position in discovery order (list page 1
+/// first, in DOM order), so output can mirror the site regardless of the
+/// order in which funds finish scraping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredUrl {
+    pub index: usize,
+    pub url: String,
+    /// The list (or sitemap) the URL was found on; `;`-joined when the same
+    /// fund was found on several.
+    pub source_list: String,
+}
+
+impl DiscoveredUrl {
+    /// Tags URLs found on `source_list` with their position in the given order.
+    pub fn index_all(urls: Vec<String>, source_list: &str) -> Vec<Self> {
+        urls.into_iter()
+            .enumerate()
+            .map(|(index, url)| Self {
+                index,
+                url,
+                source_list: source_list.to_string(),
+            })
+            .collect()
+    }
+
+    /// Collapses URLs pointing at the same fund (see `canonical_url`) into
+    /// the first one found, joining their source lists, and re-indexes the
+    /// result.
+    pub fn merge_sources(urls: Vec<Self>) -> Vec<Self> {
+        let mut merged: Vec<Self> = Vec::with_capacity(urls.len());
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        for discovered in urls {
+            let key = canonical_url(&discovered.url);
+            match positions.get(&key) {
+                Some(&position) => {
+                    let existing = &mut merged[position];
+                    for source in discovered.source_list.split(';').map(str::trim) {
+                        if !existing.source_list.split(';').map(str::trim).any(|s| s == source) {
+                            existing.source_list = format!("{}; {}", existing.source_list, source);
+                        }
+                    }
+                }
+                None => {
+                    positions.insert(key, merged.len());
+                    merged.push(discovered);
+                }
+            }
+        }
+
+        for (index, discovered) in merged.iter_mut().enumerate() {
+            discovered.index = index;
+        }
+        merged
+    }
+}
+
+/// A URL reduced to what identifies the page: no scheme, query, fragment,
+/// `www.` or trailing slash, with the host lowercased. The path keeps its
+/// case, since slugs differing only in case may be different funds.
+pub fn canonical_url(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let without_query = without_scheme.split(['?', '#']).next().unwrap_or_default();
+    let trimmed = without_query.trim_end_matches('/');
+    let (host, path) = trimmed.split_at(trimmed.find('/').unwrap_or(trimmed.len()));
+    let host = host.to_lowercase();
+    format!("{}{}", host.strip_prefix("www.").unwrap_or(&host), path)
+}
+
+/// One scraped fund, as written to every output. Its JSON Schema is printed
+/// by the `schema` subcommand.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Fund {
     pub fund_name: String,
+    /// Where `fund_name` came from: `page`, `slug` when it was derived from
+    /// the profile URL, or `none`. Anything but `page` should be reviewed.
+    pub name_source: String,
     pub fund_url: String,
-    pub aum: String,
+    /// The list(s) the fund was discovered on, `;`-joined.
+    #[serde(default)]
+    pub source_list: String,
+    /// Firm-wide assets under management, parsed from `aum_raw`. Written to
+    /// the `aum` column.
+    pub aum_value: Option<f64>,
+    /// The text the AUM was parsed from, exactly as matched on the page, so
+    /// the parsed figure can be audited and re-parsed.
+    #[serde(default)]
+    pub aum_raw: String,
+    /// ISO code of the currency the AUM was written in, `None` when the
+    /// figure had no symbol or code.
+    #[serde(default)]
+    pub aum_currency: Option<String>,
+    /// Size of the fund currently being raised, distinct from firm-wide AUM.
+    pub fund_size: Option<f64>,
+    /// ISO code of the currency the fund size was written in, `None` when
+    /// the figure had no symbol or code.
+    #[serde(default)]
+    pub fund_size_currency: Option<String>,
+    /// Typical investment per company as written, e.g. "€250k–€2M".
+    #[serde(default)]
+    pub ticket_size: String,
     pub linkedin_url: String,
+    /// The fund's own website, never a social profile or a Vestbee page.
+    #[serde(default)]
+    pub website_url: String,
+    /// Phone numbers from the profile, `;`-separated, with spaces and
+    /// punctuation stripped and any leading `+` kept.
+    #[serde(default)]
+    pub contact_phone: String,
     pub investment_geographies: String,
+    /// Recognized stages the fund invests at, e.g. "Seed, Series A",
+    /// spelled as in `normalize::INVESTMENT_STAGES`.
+    #[serde(default)]
+    pub investment_stages: String,
+    pub headquarters: String,
+    /// Normalized investment status such as "Actively investing", empty
+    /// when the profile doesn't state one.
+    #[serde(default)]
+    pub status: String,
+    /// Where a private-equity fund is in its raise, e.g. "First close",
+    /// from `normalize::CLOSE_STATUSES`. Empty for profiles not using that vocabulary.
+    #[serde(default)]
+    pub close_status: String,
+    /// Date of that close as stated: `YYYY-MM-DD`, `YYYY-MM`, `YYYY-Qn` or `YYYY`.
+    #[serde(default)]
+    pub close_date: String,
+    /// Annual management fee where disclosed, e.g. "2%"; wordier terms are
+    /// kept as written.
+    #[serde(default)]
+    pub management_fee: String,
+    /// Carried interest where disclosed, in the same form as `management_fee`.
+    #[serde(default)]
+    pub carry: String,
     pub fund_description: String,
     pub fund_portfolio: String,
+    /// Number of investments the profile states ("120+ investments"), or
+    /// failing that the number of portfolio companies found.
+    #[serde(default)]
+    pub deal_count: Option<u32>,
+    pub tags: Vec<String>,
+    /// When the profile was scraped, RFC 3339 in UTC. Timestamps in this
+    /// form sort chronologically as plain strings.
+    #[serde(default)]
+    pub scraped_at: String,
+    #[serde(default)]
+    pub confidence: FieldConfidence,
+    /// Text each extractor found before cleaning, keyed by column name.
+    /// Only written out by `--emit-raw`.
+    #[serde(skip)]
+    pub raw: BTreeMap<String, String>,
+    /// Extraction strategy that produced each filled field, keyed by column
+    /// name (`json_ld`, `table`, `slug`, ...). Only written out by `--provenance`.
+    #[serde(skip)]
+    pub provenance: BTreeMap<String, String>,
+}
+
+/// How confident the scraper is in each extracted field, from 0.0 (not
+/// found) to 1.0, based on which extraction strategy matched.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FieldConfidence {
+    pub fund_name: f32,
+    pub aum: f32,
+    pub fund_size: f32,
+    #[serde(default)]
+    pub ticket_size: f32,
+    pub linkedin_url: f32,
+    #[serde(default)]
+    pub website_url: f32,
+    #[serde(default)]
+    pub contact_phone: f32,
+    pub investment_geographies: f32,
+    #[serde(default)]
+    pub investment_stages: f32,
+    pub headquarters: f32,
+    #[serde(default)]
+    pub status: f32,
+    #[serde(default)]
+    pub close_status: f32,
+    #[serde(default)]
+    pub management_fee: f32,
+    #[serde(default)]
+    pub carry: f32,
+    pub fund_description: f32,
+    pub fund_portfolio: f32,
+    #[serde(default)]
+    pub deal_count: f32,
+    pub tags: f32,
+}
+
+impl FieldConfidence {
+    /// Looks up the confidence for a column, if that column is extracted.
+    pub fn get(&self, field: &str) -> Option<f32> {
+        let confidence = match field {
+            "fund_name" => self.fund_name,
+            "aum" => self.aum,
+            "fund_size" => self.fund_size,
+            "ticket_size" => self.ticket_size,
+            "linkedin_url" => self.linkedin_url,
+            "website_url" => self.website_url,
+            "contact_phone" => self.contact_phone,
+            "investment_geographies" => self.investment_geographies,
+            "investment_stages" => self.investment_stages,
+            "headquarters" => self.headquarters,
+            "status" => self.status,
+            "close_status" => self.close_status,
+            "management_fee" => self.management_fee,
+            "carry" => self.carry,
+            "fund_description" => self.fund_description,
+            "fund_portfolio" => self.fund_portfolio,
+            "deal_count" => self.deal_count,
+            "tags" => self.tags,
+            _ => return None,
+        };
+        Some(confidence)
+    }
+
+    /// Records the confidence for a column. Columns that aren't extracted are ignored.
+    pub fn set(&mut self, field: &str, confidence: f32) {
+        let slot = match field {
+            "fund_name" => &mut self.fund_name,
+            "aum" => &mut self.aum,
+            "fund_size" => &mut self.fund_size,
+            "ticket_size" => &mut self.ticket_size,
+            "linkedin_url" => &mut self.linkedin_url,
+            "website_url" => &mut self.website_url,
+            "contact_phone" => &mut self.contact_phone,
+            "investment_geographies" => &mut self.investment_geographies,
+            "investment_stages" => &mut self.investment_stages,
+            "headquarters" => &mut self.headquarters,
+            "status" => &mut self.status,
+            "close_status" => &mut self.close_status,
+            "management_fee" => &mut self.management_fee,
+            "carry" => &mut self.carry,
+            "fund_description" => &mut self.fund_description,
+            "fund_portfolio" => &mut self.fund_portfolio,
+            "deal_count" => &mut self.deal_count,
+            "tags" => &mut self.tags,
+            _ => return,
+        };
+        *slot = confidence;
+    }
+}
+
+/// `*_confidence` output columns, paired with the field they score.
+pub const CONFIDENCE_COLUMNS: &[(&str, &str)] = &[
+    ("fund_name", "fund_name_confidence"),
+    ("aum", "aum_confidence"),
+    ("fund_size", "fund_size_confidence"),
+    ("ticket_size", "ticket_size_confidence"),
+    ("linkedin_url", "linkedin_url_confidence"),
+    ("website_url", "website_url_confidence"),
+    ("contact_phone", "contact_phone_confidence"),
+    ("investment_geographies", "investment_geographies_confidence"),
+    ("investment_stages", "investment_stages_confidence"),
+    ("headquarters", "headquarters_confidence"),
+    ("status", "status_confidence"),
+    ("close_status", "close_status_confidence"),
+    ("management_fee", "management_fee_confidence"),
+    ("carry", "carry_confidence"),
+    ("fund_description", "fund_description_confidence"),
+    ("fund_portfolio", "fund_portfolio_confidence"),
+    ("deal_count", "deal_count_confidence"),
+    ("tags", "tags_confidence"),
+];
+
+/// The confidence columns for the given output columns, in the same order.
+pub fn confidence_columns(columns: &[&'static str]) -> Vec<&'static str> {
+    columns
+        .iter()
+        .filter_map(|column| {
+            CONFIDENCE_COLUMNS
+                .iter()
+                .find(|(field, _)| field == column)
+                .map(|(_, confidence_column)| *confidence_column)
+        })
+        .collect()
 }
 
 impl Fund {
     pub fn new() -> Self {
         Self {
             fund_name: String::new(),
+            name_source: String::new(),
             fund_url: String::new(),
-            aum: String::new(),
+            source_list: String::new(),
+            aum_value: None,
+            aum_raw: String::new(),
+            aum_currency: None,
+            fund_size: None,
+            fund_size_currency: None,
+            ticket_size: String::new(),
             linkedin_url: String::new(),
+            website_url: String::new(),
+            contact_phone: String::new(),
             investment_geographies: String::new(),
+            investment_stages: String::new(),
+            headquarters: String::new(),
+            status: String::new(),
+            close_status: String::new(),
+            close_date: String::new(),
+            management_fee: String::new(),
+            carry: String::new(),
             fund_description: String::new(),
             fund_portfolio: String::new(),
+            deal_count: None,
+            tags: Vec::new(),
+            scraped_at: String::new(),
+            confidence: FieldConfidence::default(),
+            raw: BTreeMap::new(),
+            provenance: BTreeMap::new(),
+        }
+    }
+}
+
+impl Fund {
+    /// Looks up a field by its column name. List fields are `;`-joined.
+    pub fn get_field(&self, name: &str) -> Option<Cow<'_, str>> {
+        let value = match name {
+            "fund_name" => &self.fund_name,
+            "name_source" => &self.name_source,
+            "fund_url" => &self.fund_url,
+            "source_list" => &self.source_list,
+            "aum_raw" => &self.aum_raw,
+            "ticket_size" => &self.ticket_size,
+            "linkedin_url" => &self.linkedin_url,
+            "website_url" => &self.website_url,
+            "contact_phone" => &self.contact_phone,
+            "investment_geographies" => &self.investment_geographies,
+            "investment_stages" => &self.investment_stages,
+            "headquarters" => &self.headquarters,
+            "status" => &self.status,
+            "close_status" => &self.close_status,
+            "close_date" => &self.close_date,
+            "management_fee" => &self.management_fee,
+            "carry" => &self.carry,
+            "fund_description" => &self.fund_description,
+            "fund_portfolio" => &self.fund_portfolio,
+            "scraped_at" => &self.scraped_at,
+            "aum" => {
+                return Some(Cow::Owned(
+                    self.aum_value.map(normalize::format_amount).unwrap_or_default(),
+                ))
+            }
+            "aum_currency" => return Some(Cow::Borrowed(self.aum_currency.as_deref().unwrap_or_default())),
+            "fund_size" => {
+                return Some(Cow::Owned(
+                    self.fund_size.map(normalize::format_amount).unwrap_or_default(),
+                ))
+            }
+            "fund_size_currency" => {
+                return Some(Cow::Borrowed(self.fund_size_currency.as_deref().unwrap_or_default()))
+            }
+            "deal_count" => {
+                return Some(Cow::Owned(
+                    self.deal_count.map(|count| count.to_string()).unwrap_or_default(),
+                ))
+            }
+            "tags" => return Some(Cow::Owned(self.tags.join("; "))),
+            other => {
+                let field = other.strip_suffix("_confidence")?;
+                let confidence = self.confidence.get(field)?;
+                return Some(Cow::Owned(format!("{:.1}", confidence)));
+            }
+        };
+        Some(Cow::Borrowed(value))
+    }
+
+    /// Sets a field from its column text form, the inverse of `get_field`.
+    pub fn set_field(&mut self, name: &str, value: String) -> Result<()> {
+        match name {
+            "fund_name" => self.fund_name = value,
+            "name_source" => self.name_source = value,
+            "fund_url" => self.fund_url = value,
+            "source_list" => self.source_list = value,
+            "aum" => self.aum_value = value.parse().ok(),
+            "aum_raw" => self.aum_raw = value,
+            "aum_currency" => self.aum_currency = Some(value).filter(|code| !code.is_empty()),
+            "ticket_size" => self.ticket_size = value,
+            "linkedin_url" => self.linkedin_url = value,
+            "website_url" => self.website_url = value,
+            "contact_phone" => self.contact_phone = value,
+            "investment_geographies" => self.investment_geographies = value,
+            "investment_stages" => self.investment_stages = value,
+            "headquarters" => self.headquarters = value,
+            "status" => self.status = value,
+            "close_status" => self.close_status = value,
+            "close_date" => self.close_date = value,
+            "management_fee" => self.management_fee = value,
+            "carry" => self.carry = value,
+            "fund_description" => self.fund_description = value,
+            "fund_portfolio" => self.fund_portfolio = value,
+            "scraped_at" => self.scraped_at = value,
+            "fund_size" => self.fund_size = value.parse().ok(),
+            "fund_size_currency" => self.fund_size_currency = Some(value).filter(|code| !code.is_empty()),
+            "deal_count" => self.deal_count = value.parse().ok(),
+            "tags" => {
+                self.tags = value
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(String::from)
+                    .collect()
+            }
+            other => bail!("Unknown field '{}'", other),
+        }
+        Ok(())
+    }
+}
+
+impl Fund {
+    /// Normalizes whitespace in the text fields and drops empty or repeated
+    /// tags, so every exporter sees the same clean values.
+    pub fn sanitize(&mut self) {
+        for value in [
+            &mut self.fund_name,
+            &mut self.name_source,
+            &mut self.fund_url,
+            &mut self.source_list,
+            &mut self.aum_raw,
+            &mut self.ticket_size,
+            &mut self.linkedin_url,
+            &mut self.website_url,
+            &mut self.contact_phone,
+            &mut self.investment_geographies,
+            &mut self.investment_stages,
+            &mut self.headquarters,
+            &mut self.status,
+            &mut self.close_status,
+            &mut self.close_date,
+            &mut self.management_fee,
+            &mut self.carry,
+            &mut self.fund_description,
+            &mut self.fund_portfolio,
+            &mut self.scraped_at,
+        ] {
+            *value = collapse_whitespace(value);
+        }
+
+        let mut tags: Vec<String> = Vec::with_capacity(self.tags.len());
+        for tag in self.tags.drain(..).map(|tag| collapse_whitespace(&tag)) {
+            if !tag.is_empty() && !tags.iter().any(|seen| seen.eq_ignore_ascii_case(&tag)) {
+                tags.push(tag);
+            }
+        }
+        self.tags = tags;
+
+        // A negative or NaN amount can only come from a parsing slip
+        self.aum_value = self.aum_value.filter(|aum| *aum >= 0.0);
+        self.fund_size = self.fund_size.filter(|size| *size >= 0.0);
+    }
+
+    /// Re-derives the normalized fields from what was extracted, then
+    /// sanitizes. Amounts are re-parsed from the raw text in `raw` when it
+    /// was kept (or, for AUM, from `aum_raw`), so this can be re-run on stored funds after the rules
+    /// change; it never touches the page, and running it twice is a no-op.
+    pub fn normalize(&mut self) {
+        let aum_raw = self.raw.get("aum").cloned().unwrap_or_else(|| self.aum_raw.clone());
+        if let Some((amount, currency)) = normalize::parse_aum(&aum_raw) {
+            self.aum_value = Some(amount);
+            self.aum_currency = Some(currency).filter(|code| !code.is_empty());
+            self.aum_raw = aum_raw;
+        }
+        if let Some((amount, currency)) = self.raw.get("fund_size").and_then(|raw| normalize::parse_aum(raw)) {
+            self.fund_size = Some(amount);
+            self.fund_size_currency = Some(currency).filter(|code| !code.is_empty());
+        }
+
+        // Only recognized values are kept, as when extracting
+        if !self.investment_geographies.is_empty() {
+            self.investment_geographies = normalize::geographies(&self.investment_geographies).join(", ");
+        }
+        if !self.ticket_size.is_empty() {
+            self.ticket_size = normalize::ticket_size(&self.ticket_size).unwrap_or_default();
+        }
+        if !self.investment_stages.is_empty() {
+            self.investment_stages = normalize::stages(&self.investment_stages).join(", ");
+        }
+        if !self.status.is_empty() {
+            self.status = normalize::status(&self.status).unwrap_or_default().to_string();
+        }
+        if !self.close_status.is_empty() {
+            self.close_status = normalize::close_status(&self.close_status).unwrap_or_default().to_string();
+        }
+        if let Some(date) = normalize::close_date(&self.close_date) {
+            self.close_date = date;
+        }
+
+        if normalize::website(&self.website_url).is_none() {
+            self.website_url.clear();
+        }
+        if !self.contact_phone.is_empty() {
+            self.contact_phone = normalize::phones(self.contact_phone.split(';')).join(";");
         }
+
+        // Terms that aren't a plain percentage are kept as written
+        for term in [&mut self.management_fee, &mut self.carry] {
+            if let Some(percentage) = normalize::normalize_percentage(term) {
+                *term = percentage;
+            }
+        }
+
+        self.sanitize();
+    }
+
+    /// Checks the fund against what downstream consumers rely on. Errors
+    /// make the record unusable, warnings flag values worth a look.
+    pub fn validate(&self) -> Vec<Problem> {
+        let mut problems = Vec::new();
+
+        if self.fund_url.is_empty() {
+            problems.push(Problem::error("fund_url", "missing"));
+        } else if !is_web_url(&self.fund_url) {
+            problems.push(Problem::error("fund_url", format!("not an http(s) URL: {}", self.fund_url)));
+        }
+        if !self.linkedin_url.is_empty() && !is_web_url(&self.linkedin_url) {
+            problems.push(Problem::error(
+                "linkedin_url",
+                format!("not an http(s) URL: {}", self.linkedin_url),
+            ));
+        }
+        if !self.website_url.is_empty() && !is_web_url(&self.website_url) {
+            problems.push(Problem::error(
+                "website_url",
+                format!("not an http(s) URL: {}", self.website_url),
+            ));
+        }
+
+        if self.fund_name.is_empty() {
+            problems.push(Problem::warning("fund_name", "missing"));
+        }
+        if !self.scraped_at.is_empty() && DateTime::parse_from_rfc3339(&self.scraped_at).is_err() {
+            problems.push(Problem::warning(
+                "scraped_at",
+                format!("not an RFC 3339 timestamp: {}", self.scraped_at),
+            ));
+        }
+
+        problems
     }
 }
 
+fn is_web_url(url: &str) -> bool {
+    reqwest::Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.host().is_some())
+}
+
+/// Something wrong with a fund, found by `Fund::validate`.
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub field: &'static str,
+    pub message: String,
+    /// Hard errors make a record unfit to load; the rest are warnings.
+    pub is_error: bool,
+}
+
+impl Problem {
+    pub fn error(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+            is_error: true,
+        }
+    }
+
+    pub fn warning(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+            is_error: false,
+        }
+    }
+}
+
+/// Trims and turns runs of whitespace (including newlines) into single spaces.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Assembles a `Fund` field by field. `build` normalizes the result and
+/// checks its invariants, so no half-normalized fund leaves the scraper.
+#[derive(Debug, Default)]
+pub struct FundBuilder {
+    fund: Fund,
+}
+
+impl FundBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.fund.fund_name = name.into();
+        self
+    }
+
+    pub fn name_source(mut self, source: impl Into<String>) -> Self {
+        self.fund.name_source = source.into();
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.fund.fund_url = url.into();
+        self
+    }
+
+    pub fn scraped_at(mut self, at: DateTime<Utc>) -> Self {
+        self.fund.scraped_at = at.to_rfc3339_opts(SecondsFormat::Secs, true);
+        self
+    }
+
+    /// Sets a field by column name from its text form, see `Fund::set_field`.
+    pub fn field(mut self, name: &str, value: String) -> Result<Self> {
+        self.fund.set_field(name, value)?;
+        Ok(self)
+    }
+
+    pub fn confidence(mut self, field: &str, confidence: f32) -> Self {
+        self.fund.confidence.set(field, confidence);
+        self
+    }
+
+    /// Records the pre-cleaning text for a field; empty text is skipped.
+    pub fn raw(mut self, field: &str, raw: String) -> Self {
+        if !raw.is_empty() {
+            self.fund.raw.insert(field.to_string(), raw);
+        }
+        self
+    }
+
+    /// Records the strategy that produced a field's value.
+    pub fn provenance(mut self, field: &str, strategy: &str) -> Self {
+        self.fund.provenance.insert(field.to_string(), strategy.to_string());
+        self
+    }
+
+    /// Current value of a field, for decisions made while building.
+    pub fn get(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.fund.get_field(name)
+    }
+
+    pub fn build(self) -> Result<Fund> {
+        let mut fund = self.fund;
+        fund.normalize();
+        // Fields normalization emptied have nothing to account for
+        let provenance = std::mem::take(&mut fund.provenance);
+        fund.provenance = provenance
+            .into_iter()
+            .filter(|(field, _)| fund.get_field(field).is_some_and(|value| !value.is_empty()))
+            .collect();
+        if fund.fund_url.is_empty() {
+            bail!("Fund has no URL");
+        }
+        Ok(fund)
+    }
+}
+
+/// Key casing for JSON output. Field names are snake_case in Rust, so
+/// camelCase is produced by renaming keys at write time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonCase {
+    #[default]
+    Snake,
+    Camel,
+}
+
+impl FromStr for JsonCase {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "snake" => Ok(Self::Snake),
+            "camel" => Ok(Self::Camel),
+            other => bail!("Unknown JSON case '{}', expected 'snake' or 'camel'", other),
+        }
+    }
+}
+
+impl Fund {
+    /// Serializes the fund to a JSON object with keys in the requested case.
+    pub fn to_json_value(&self, case: JsonCase) -> Result<Value> {
+        let value = serde_json::to_value(self)?;
+        match (case, value) {
+            (JsonCase::Camel, Value::Object(map)) => Ok(Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| (snake_to_camel(&key), value))
+                    .collect(),
+            )),
+            (_, value) => Ok(value),
+        }
+    }
+}
+
+fn snake_to_camel(key: &str) -> String {
+    let mut camel = String::with_capacity(key.len());
+    let mut upper_next = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper_next = true;
+        } else if upper_next {
+            camel.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+/// Parses a comma-separated list of column names, keeping the given order.
+pub fn parse_columns(list: &str) -> Result<Vec<&'static str>> {
+    let mut columns = Vec::new();
+    for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+        match FIELD_NAMES.iter().find(|field| **field == name) {
+            Some(field) => columns.push(*field),
+            None => bail!(
+                "Unknown column '{}'. Valid columns are: {}",
+                name,
+                FIELD_NAMES.join(", ")
+            ),
+        }
+    }
+    if columns.is_empty() {
+        bail!("No columns given. Valid columns are: {}", FIELD_NAMES.join(", "));
+    }
+    Ok(columns)
+}
+
 impl Default for Fund {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_url_lowercases_only_the_host() {
+        assert_eq!(
+            canonical_url("https://WWW.Vestbee.com/lp/Acme-Capital/?ref=list#team"),
+            "vestbee.com/lp/Acme-Capital"
+        );
+        assert_eq!(canonical_url("http://vestbee.com/lp/acme-capital"), "vestbee.com/lp/acme-capital");
+        assert_ne!(
+            canonical_url("https://www.vestbee.com/lp/Acme"),
+            canonical_url("https://www.vestbee.com/lp/acme")
+        );
+    }
+
+    #[test]
+    fn build_collapses_whitespace() {
+        let fund = FundBuilder::new()
+            .url(" https://www.vestbee.com/lp/acme-capital\n")
+            .name("  Acme \n  Capital ")
+            .field("headquarters", "\tBerlin,   Germany ".to_string())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(fund.fund_url, "https://www.vestbee.com/lp/acme-capital");
+        assert_eq!(fund.fund_name, "Acme Capital");
+        assert_eq!(fund.headquarters, "Berlin, Germany");
+    }
+
+    #[test]
+    fn build_drops_empty_and_repeated_tags() {
+        let fund = FundBuilder::new()
+            .url("https://www.vestbee.com/lp/acme-capital")
+            .field("tags", "Fintech;  fintech ; ;SaaS".to_string())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(fund.tags, ["Fintech", "SaaS"]);
+    }
+
+    #[test]
+    fn build_drops_provenance_of_emptied_fields() {
+        let fund = FundBuilder::new()
+            .url("https://www.vestbee.com/lp/acme-capital")
+            .name("Acme Capital")
+            .provenance("fund_name", "heading")
+            .field("headquarters", "   ".to_string())
+            .unwrap()
+            .provenance("headquarters", "fact")
+            .build()
+            .unwrap();
+        assert_eq!(fund.provenance.get("fund_name").map(String::as_str), Some("heading"));
+        assert!(!fund.provenance.contains_key("headquarters"));
+    }
+
+    #[test]
+    fn build_requires_a_url() {
+        assert!(FundBuilder::new().name("Acme Capital").build().is_err());
+        assert!(FundBuilder::new().url("  ").build().is_err());
+    }
+}
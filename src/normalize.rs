@@ -0,0 +1,458 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// The word right after the digits of a lowercased money figure, once
+/// anything before its first digit is dropped.
+static AMOUNT_SUFFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[\d.,\s]*([a-z]+)\b").unwrap());
+
+/// Parses a money figure such as `€3,8B`, `$250M+` or `1.000.000,50 EUR`
+/// into a plain number, expanding T/B/M/K suffixes and handling both US and
+/// European digit separators. A lone comma before exactly three digits is a
+/// thousands separator (`€250,000`) unless a suffix follows (`€1,125M`). The
+/// currency itself is ignored.
+pub fn parse_amount(raw: &str) -> Option<f64> {
+    let lower = raw.to_lowercase();
+
+    // The multiplier is the first word after the number, as in "3,8B" or
+    // "500 thousand"; a currency code there ("250 EUR") matches none
+    let digits = lower.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let suffix = AMOUNT_SUFFIX
+        .captures(digits)
+        .and_then(|captures| captures.get(1))
+        .map(|word| word.as_str());
+    let multiplier = match suffix {
+        Some("t" | "tn" | "trn" | "trillion" | "trillions") => 1_000_000_000_000.0,
+        Some("b" | "bn" | "bln" | "billion" | "billions") => 1_000_000_000.0,
+        Some("m" | "mn" | "mm" | "mio" | "mln" | "million" | "millions") => 1_000_000.0,
+        Some("k" | "thousand" | "thousands") => 1_000.0,
+        _ => 1.0,
+    };
+    let has_suffix = multiplier != 1.0;
+
+    let mut number: String = digits
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',' || *c == '.' || c.is_whitespace())
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    number = number.trim_end_matches(['.', ',']).to_string();
+
+    if number.contains(',') && number.contains('.') {
+        // Whichever separator comes last is the decimal separator
+        let last_comma = number.rfind(',').unwrap_or_default();
+        let last_period = number.rfind('.').unwrap_or_default();
+        if last_comma > last_period {
+            // European format: 1.000.000,50
+            number = number.replace('.', "").replace(',', ".");
+        } else {
+            // US format: 1,000,000.50
+            number = number.replace(',', "");
+        }
+    } else if number.contains(',') {
+        let parts: Vec<&str> = number.split(',').collect();
+        if parts.len() == 2 && (parts[1].len() < 3 || (parts[1].len() == 3 && has_suffix)) {
+            // Likely European decimal: 3,8 or 100,5, or 1,125M
+            number = number.replace(',', ".");
+        } else {
+            // Likely thousands separator: 1,000,000
+            number = number.replace(',', "");
+        }
+    } else if number.matches('.').count() > 1 {
+        // European thousands separators without decimals: 1.000.000
+        number = number.replace('.', "");
+    }
+
+    number.parse::<f64>().ok().map(|value| value * multiplier)
+}
+
+/// Currency a money figure was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Currency {
+    Eur,
+    Usd,
+    Gbp,
+}
+
+impl Currency {
+    /// ISO 4217 code, as written to output.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Eur => "EUR",
+            Self::Usd => "USD",
+            Self::Gbp => "GBP",
+        }
+    }
+
+    /// The inverse of `code`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "EUR" => Some(Self::Eur),
+            "USD" => Some(Self::Usd),
+            "GBP" => Some(Self::Gbp),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Eur => "€",
+            Self::Usd => "$",
+            Self::Gbp => "£",
+        }
+    }
+}
+
+// Currency codes as whole words; a digit may precede them, as in "250EUR",
+// but a letter may not, so "Europe" isn't read as euros
+static EUR_WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)(?:^|[^a-z])eur(?:os?)?(?:[^a-z]|$)").unwrap());
+static USD_WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)(?:^|[^a-z])usd(?:[^a-z]|$)").unwrap());
+static GBP_WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)(?:^|[^a-z])gbp(?:[^a-z]|$)").unwrap());
+
+/// Detects the currency of a raw money figure from its symbol (`€`, `$`,
+/// `£`) or code (`EUR`, `USD`, `GBP`). `None` for bare figures like `3B`.
+pub fn detect_currency(raw: &str) -> Option<Currency> {
+    if raw.contains('€') || EUR_WORD.is_match(raw) {
+        Some(Currency::Eur)
+    } else if raw.contains('$') || USD_WORD.is_match(raw) {
+        Some(Currency::Usd)
+    } else if raw.contains('£') || GBP_WORD.is_match(raw) {
+        Some(Currency::Gbp)
+    } else {
+        None
+    }
+}
+
+/// Parses a money figure such as an AUM into its amount and the ISO code of
+/// its currency, e.g. `€1.5B` -> `(1500000000.0, "EUR")`. The code is empty
+/// when the figure names no currency, as in `3,8B`.
+pub fn parse_aum(raw: &str) -> Option<(f64, String)> {
+    let amount = parse_amount(raw)?;
+    let currency = detect_currency(raw).map(|currency| currency.code().to_string());
+    Some((amount, currency.unwrap_or_default()))
+}
+
+/// Formats a parsed amount the way it is written to output: whole units, no separators.
+pub fn format_amount(value: f64) -> String {
+    format!("{:.0}", value.round())
+}
+
+/// Normalizes a bare percentage such as `2 %` or `1,5%` to `2%` / `1.5%`.
+/// `None` for anything more involved, like "2% on committed capital".
+pub fn normalize_percentage(raw: &str) -> Option<String> {
+    let number = raw.trim().strip_suffix('%')?.trim().replace(',', ".");
+    let value: f64 = number.parse().ok()?;
+    (value.is_finite() && value >= 0.0).then(|| format!("{}%", value))
+}
+
+/// Regions and countries recognized as investment geographies.
+pub const VALID_GEOGRAPHIES: &[&str] = &[
+    "Global", "Europe", "Asia", "Africa", "America", "Americas",
+    "North America", "South America", "Latin America",
+    "USA", "US", "United States", "UK", "United Kingdom",
+    "Germany", "France", "Spain", "Italy", "Poland",
+    "Ireland", "Netherlands", "Belgium", "Switzerland",
+    "Austria", "Sweden", "Norway", "Denmark", "Finland",
+    "Portugal", "Greece", "Czech Republic", "Hungary",
+    "Romania", "Bulgaria", "Croatia", "Serbia", "Slovenia",
+    "Estonia", "Latvia", "Lithuania", "Luxembourg",
+    "Canada", "Mexico", "Brazil", "Argentina", "Chile",
+    "China", "Japan", "India", "Singapore", "Australia",
+    "Israel", "Turkey", "Russia", "Ukraine",
+    "EMEA", "APAC", "LATAM", "NAMER", "MENA",
+    "CEE", "DACH", "Nordics", "Benelux",
+    "Central Europe", "Eastern Europe", "Western Europe",
+    "Northern Europe", "Southern Europe",
+];
+
+/// The recognized geographies in a labeled value like "CEE, DACH / Nordics",
+/// in the order written and spelled as in `VALID_GEOGRAPHIES`.
+pub fn geographies(text: &str) -> Vec<&'static str> {
+    let mut found = Vec::new();
+    for part in text.split([',', ';', '/', '\n']) {
+        let part = part.trim();
+        let geography = VALID_GEOGRAPHIES
+            .iter()
+            .find(|geography| geography.eq_ignore_ascii_case(part));
+        if let Some(geography) = geography {
+            if !found.contains(geography) {
+                found.push(*geography);
+            }
+        }
+    }
+    found
+}
+
+/// Recognized investment stages: the normalized name and the texts
+/// (lowercase, hyphens as spaces) that mean it.
+pub const INVESTMENT_STAGES: &[(&str, &[&str])] = &[
+    ("Pre-Seed", &["pre seed", "preseed"]),
+    ("Seed", &["seed", "seed stage"]),
+    ("Series A", &["series a"]),
+    ("Series B", &["series b"]),
+    ("Series C", &["series c"]),
+    ("Series D+", &["series d", "series d+", "series e", "series e+"]),
+    ("Early Stage", &["early stage", "early"]),
+    ("Growth", &["growth", "growth stage", "growth equity", "expansion"]),
+    ("Late Stage", &["late stage", "late", "pre ipo"]),
+    ("Buyout", &["buyout", "buy out", "lbo"]),
+];
+
+/// The recognized stages in a labeled value like "Pre-seed, Seed / Series A",
+/// in the order written, each once and spelled as in `INVESTMENT_STAGES`.
+pub fn stages(text: &str) -> Vec<&'static str> {
+    let mut found = Vec::new();
+    for part in text.split([',', ';', '/', '|', '&', '\n']) {
+        let part = part.trim().trim_end_matches('.').replace(['-', '_'], " ").to_lowercase();
+        let part = part.split_whitespace().collect::<Vec<_>>().join(" ");
+        let stage = INVESTMENT_STAGES
+            .iter()
+            .find(|(_, phrases)| phrases.contains(&part.as_str()))
+            .map(|(stage, _)| *stage);
+        if let Some(stage) = stage {
+            if !found.contains(&stage) {
+                found.push(stage);
+            }
+        }
+    }
+    found
+}
+
+/// Recognized investment statuses: the normalized value and the texts (lowercase)
+/// that mean it. Profiles showing anything else get no status rather than a guess.
+pub const INVESTMENT_STATUSES: &[(&str, &[&str])] = &[
+    (
+        "Actively investing",
+        &["actively investing", "active", "currently investing", "open to new investments", "investing"],
+    ),
+    ("Raising", &["raising", "fundraising", "currently raising", "now raising"]),
+    ("Fully deployed", &["fully deployed", "deployed", "fully invested"]),
+    ("Not investing", &["not investing", "no longer investing", "inactive", "not actively investing"]),
+];
+
+/// Maps a status text onto one of `INVESTMENT_STATUSES`. The whole text has
+/// to match, so "Raising €50M" (a fund size) is not read as a status.
+pub fn status(text: &str) -> Option<&'static str> {
+    let text = text
+        .trim()
+        .trim_end_matches(['.', '!'])
+        .to_lowercase();
+    INVESTMENT_STATUSES
+        .iter()
+        .find(|(_, phrases)| phrases.contains(&text.as_str()))
+        .map(|(status, _)| *status)
+}
+
+/// Fewest and most digits in a phone number; E.164 allows at most 15.
+const PHONE_DIGITS: (usize, usize) = (7, 15);
+
+/// Hosts whose links are profiles on a network or the listing itself,
+/// never a fund's own website. Subdomains count too.
+const NOT_WEBSITES: &[&str] = &[
+    "vestbee.com",
+    "linkedin.com",
+    "twitter.com",
+    "x.com",
+    "facebook.com",
+    "instagram.com",
+    "youtube.com",
+    "crunchbase.com",
+];
+
+/// Accepts `raw` as a fund's own website: an http(s) URL not on a social
+/// network or Vestbee itself, so the profile page can never be stored as
+/// the website. Returned as written, trimmed.
+pub fn website(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let url = reqwest::Url::parse(raw).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?.to_lowercase();
+    let excluded = NOT_WEBSITES
+        .iter()
+        .any(|domain| host == *domain || host.ends_with(&format!(".{}", domain)));
+    (!excluded).then(|| raw.to_string())
+}
+
+/// Strips spaces and punctuation from a phone number, keeping a leading `+`
+/// (written `00` in some countries). `None` for anything that doesn't have
+/// a phone number's length or contains letters.
+pub fn phone(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let (plus, rest) = match raw.strip_prefix('+').or_else(|| raw.strip_prefix("00")) {
+        Some(rest) => (true, rest),
+        None => (false, raw),
+    };
+    if !rest.chars().all(|c| c.is_ascii_digit() || " -.()/\u{a0}".contains(c)) {
+        return None;
+    }
+    let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+    if digits.len() < PHONE_DIGITS.0 || digits.len() > PHONE_DIGITS.1 {
+        return None;
+    }
+    Some(if plus { format!("+{}", digits) } else { digits })
+}
+
+/// Normalizes each number with `phone`, dropping invalid ones and repeats.
+pub fn phones<'a>(raw: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+    for number in raw.into_iter().filter_map(phone) {
+        if !found.contains(&number) {
+            found.push(number);
+        }
+    }
+    found
+}
+
+/// Private-equity fund lifecycle stages: the normalized value and the phrases
+/// (lowercase) that mean it. Only multi-word phrases, so "closed" or
+/// "invested" in a VC profile's prose doesn't register.
+pub const CLOSE_STATUSES: &[(&str, &[&str])] = &[
+    ("First close", &["first close", "first closing", "initial close", "initial closing"]),
+    ("Interim close", &["interim close", "second close", "second closing", "third close"]),
+    ("Final close", &["final close", "final closing", "closed fund", "fundraising closed"]),
+    ("Fully invested", &["fully invested", "fully committed", "investment period ended"]),
+];
+
+/// The close status a text mentions, checked in `CLOSE_STATUSES` order.
+pub fn close_status(text: &str) -> Option<&'static str> {
+    let text = text.to_lowercase();
+    CLOSE_STATUSES
+        .iter()
+        .find(|(_, phrases)| phrases.iter().any(|phrase| text.contains(phrase)))
+        .map(|(status, _)| *status)
+}
+
+const MONTHS: &[&str] = &[
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+static NORMALIZED_DATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{4}(?:-\d{2}(?:-\d{2})?|-Q[1-4])?$").unwrap());
+static ISO_DATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(\d{4})-(\d{2})-(\d{2})\b").unwrap());
+static NUMERIC_DATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(\d{1,2})[./](\d{1,2})[./](\d{4})\b").unwrap());
+static MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:(\d{1,2})(?:st|nd|rd|th)?\s+)?(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*\.?\s+(?:(\d{1,2})(?:st|nd|rd|th)?,?\s+)?(\d{4})\b").unwrap()
+});
+static QUARTER_DATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bQ([1-4])\s*'?(\d{4})\b").unwrap());
+static YEAR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b((?:19|20)\d{2})\b").unwrap());
+
+/// The first date in a text, as precise as it was written: `2023-03-15`,
+/// `2023-03`, `2023-Q1` or `2023`. Numeric dates are read day first, as
+/// written on European profiles.
+pub fn close_date(text: &str) -> Option<String> {
+    // Already normalized, and a bare `2023-03` would otherwise lose its month
+    if NORMALIZED_DATE.is_match(text.trim()) {
+        return Some(text.trim().to_string());
+    }
+    if let Some(caps) = ISO_DATE.captures(text) {
+        return Some(format!("{}-{}-{}", &caps[1], &caps[2], &caps[3]));
+    }
+    if let Some(caps) = NUMERIC_DATE.captures(text) {
+        let (day, month): (u32, u32) = (caps[1].parse().ok()?, caps[2].parse().ok()?);
+        if (1..=31).contains(&day) && (1..=12).contains(&month) {
+            return Some(format!("{}-{:02}-{:02}", &caps[3], month, day));
+        }
+    }
+    if let Some(caps) = MONTH_DATE.captures(text) {
+        let month = MONTHS.iter().position(|month| caps[2].eq_ignore_ascii_case(month))? + 1;
+        let day = caps.get(1).or(caps.get(3)).and_then(|day| day.as_str().parse::<u32>().ok());
+        return Some(match day.filter(|day| (1..=31).contains(day)) {
+            Some(day) => format!("{}-{:02}-{:02}", &caps[4], month, day),
+            None => format!("{}-{:02}", &caps[4], month),
+        });
+    }
+    if let Some(caps) = QUARTER_DATE.captures(text) {
+        return Some(format!("{}-Q{}", &caps[2], &caps[1]));
+    }
+    YEAR.captures(text).map(|caps| caps[1].to_string())
+}
+
+/// Most characters kept for a ticket size; longer text is a sentence, not a figure.
+const TICKET_SIZE_MAX_LEN: usize = 60;
+
+static TICKET_PREFIX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:(?:ticket|check|cheque|investment)\s*sizes?|tickets?)\s*[:\-–]?\s*|^(?:typically|usually|average|avg\.?|approx\.?|approximately)\s+").unwrap()
+});
+static TICKET_SUFFIX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\s*(?:per|/)\s*(?:deal|investment|company|round|ticket)\.?$").unwrap());
+
+/// A ticket size as written ("€250k–€2M", "$1M"), with any label, hedging
+/// word or "per deal" tail stripped. Ranges are kept as ranges. `None` when
+/// no figure is left.
+pub fn ticket_size(text: &str) -> Option<String> {
+    let mut value = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    // A label can be followed by a hedge, as in "Ticket size: typically €1M"
+    while let Some(prefix) = TICKET_PREFIX.find(&value).filter(|prefix| !prefix.is_empty()) {
+        value = value[prefix.end()..].to_string();
+    }
+    let value = TICKET_SUFFIX.replace(&value, "");
+    let value = value.trim_matches(|c: char| c.is_whitespace() || ".,;:".contains(c));
+    (value.chars().any(|c| c.is_ascii_digit()) && value.chars().count() <= TICKET_SIZE_MAX_LEN)
+        .then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_aum_with_suffixes_and_currencies() {
+        assert_eq!(parse_aum("€1.5B"), Some((1_500_000_000.0, "EUR".to_string())));
+        assert_eq!(parse_aum("$250M"), Some((250_000_000.0, "USD".to_string())));
+        assert_eq!(parse_aum("1.000.000,50 EUR"), Some((1_000_000.5, "EUR".to_string())));
+        assert_eq!(parse_aum("3,8B"), Some((3_800_000_000.0, String::new())));
+    }
+
+    #[test]
+    fn records_the_currency_written() {
+        assert_eq!(parse_aum("$500M"), Some((500_000_000.0, "USD".to_string())));
+        assert_eq!(parse_aum("£2B"), Some((2_000_000_000.0, "GBP".to_string())));
+        assert_eq!(parse_aum("3B"), Some((3_000_000_000.0, String::new())));
+    }
+
+    #[test]
+    fn currency_codes_only_match_whole_words() {
+        assert_eq!(detect_currency("250EUR"), Some(Currency::Eur));
+        assert_eq!(detect_currency("USD 40M"), Some(Currency::Usd));
+        assert_eq!(detect_currency("500M in Europe"), None);
+        assert_eq!(parse_amount("2B across Europe"), Some(2_000_000_000.0));
+    }
+
+    #[test]
+    fn lone_comma_before_three_digits_is_a_thousands_separator() {
+        assert_eq!(parse_amount("€250,000"), Some(250_000.0));
+        assert_eq!(parse_amount("€1,125M"), Some(1_125_000.0));
+        assert_eq!(parse_amount("100,5"), Some(100.5));
+    }
+
+    #[test]
+    fn matches_whole_suffix_words() {
+        assert_eq!(parse_amount("€500 thousand"), Some(500_000.0));
+        assert_eq!(parse_amount("$2 billion"), Some(2_000_000_000.0));
+        assert_eq!(parse_amount("€40mn"), Some(40_000_000.0));
+        assert_eq!(parse_amount("€250,000 (2020: 3M)"), Some(250_000.0));
+    }
+
+    /// The text of an HTML snippet, as the page would render it.
+    fn text_of(html: &str) -> String {
+        Regex::new(r"<[^>]+>").unwrap().replace_all(html, " ").into_owned()
+    }
+
+    #[test]
+    fn reads_ticket_sizes_from_labeled_snippets() {
+        let snippets = [
+            ("<p><strong>Ticket size:</strong> €250k–€2M</p>", "€250k–€2M"),
+            ("<div><span>Check size</span> <span>typically $1M per deal.</span></div>", "$1M"),
+            ("<li>Investment sizes – £500k / company</li>", "£500k"),
+        ];
+        for (html, expected) in snippets {
+            assert_eq!(ticket_size(&text_of(html)).as_deref(), Some(expected), "{}", html);
+        }
+    }
+
+    #[test]
+    fn ticket_size_needs_a_figure() {
+        assert_eq!(ticket_size(&text_of("<p>Ticket size: varies</p>")), None);
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::{Context, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// An output file, optionally gzip-compressed. Call `finish` when done so a
+/// compressed stream gets its trailer and isn't left truncated.
+pub enum OutputFile {
+    Plain(File),
+    Gzip(GzEncoder<File>),
+}
+
+impl OutputFile {
+    pub fn create(path: &Path, gzip: bool) -> Result<Self> {
+        let file = File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+        Ok(if gzip {
+            Self::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            Self::Plain(file)
+        })
+    }
+
+    /// Opens `path` to add to its end, creating it if missing. Plain files
+    /// only: a gzip stream can't be appended to as it is.
+    pub fn append(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        Ok(Self::Plain(file))
+    }
+
+    /// Asks the OS to write everything handed to it so far through to disk.
+    /// Data still buffered in the gzip encoder needs a `flush` first.
+    pub fn sync_all(&self) -> Result<()> {
+        match self {
+            Self::Plain(file) => file.sync_all()?,
+            Self::Gzip(encoder) => encoder.get_ref().sync_all()?,
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<()> {
+        match self {
+            Self::Plain(mut file) => file.flush()?,
+            Self::Gzip(encoder) => {
+                encoder.finish()?.flush()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Write for OutputFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(file) => file.write(buf),
+            Self::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(file) => file.flush(),
+            Self::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// `data/vestbee_funds.csv` -> `data/vestbee_funds.csv.gz` when compressing.
+pub fn output_path(path: &Path, gzip: bool) -> PathBuf {
+    if !gzip || path.extension().is_some_and(|ext| ext == "gz") {
+        return path.to_path_buf();
+    }
+    let mut name = path.as_os_str().to_owned();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+/// Writes `path` via `write`, which is handed a temporary sibling path
+/// (`<name>.tmp`). Only once `write` succeeds is the file renamed into
+/// place, which is atomic on the same filesystem, so readers never see a
+/// half-written file.
+pub fn write_atomically<T>(path: &Path, write: impl FnOnce(&Path) -> Result<T>) -> Result<T> {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    match write(&tmp_path) {
+        Ok(value) => {
+            fs::rename(&tmp_path, path)
+                .with_context(|| format!("Failed to move {} into place", path.display()))?;
+            Ok(value)
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&tmp_path);
+            Err(e)
+        }
+    }
+}
+
+/// `data/vestbee_funds.csv` -> `data/vestbee_funds_0002.csv` for part 2.
+pub fn part_path(path: &Path, part: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!("{}_{:04}.{}", stem, part, ext.to_string_lossy()),
+        None => format!("{}_{:04}", stem, part),
+    };
+    path.with_file_name(name)
+}
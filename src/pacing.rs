@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+/// Decides how long to wait between funds, so the "no pause after the last
+/// fund" rule lives in one place however the URL list was built or cut.
+#[derive(Debug, Clone, Copy)]
+pub struct Pacer {
+    /// `None` when pacing is governed elsewhere, e.g. a rate limiter.
+    delay: Option<Duration>,
+}
+
+impl Pacer {
+    /// Waits `delay` after each fund. Each worker pauses on its own, so
+    /// with several workers every one keeps the pace of a sequential run.
+    pub fn new(delay: Duration) -> Self {
+        Self { delay: Some(delay) }
+    }
+
+    /// Never waits; a rate limiter paces requests instead.
+    pub fn rate_limited() -> Self {
+        Self { delay: None }
+    }
+
+    /// The wait after the fund at `position` (0-based) out of `total`.
+    pub fn delay_after(&self, position: usize, total: usize) -> Option<Duration> {
+        if position + 1 >= total {
+            return None;
+        }
+        self.delay
+    }
+
+    pub async fn pause_after(&self, position: usize, total: usize) {
+        if let Some(delay) = self.delay_after(position, total) {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_fund_list_never_pauses() {
+        assert_eq!(Pacer::new(Duration::from_secs(2)).delay_after(0, 1), None);
+    }
+
+    #[test]
+    fn pauses_between_funds_but_not_after_the_last() {
+        let pacer = Pacer::new(Duration::from_secs(2));
+        assert_eq!(pacer.delay_after(0, 3), Some(Duration::from_secs(2)));
+        assert_eq!(pacer.delay_after(2, 3), None);
+    }
+
+    #[test]
+    fn rate_limited_pacer_never_pauses() {
+        assert_eq!(Pacer::rate_limited().delay_after(0, 3), None);
+    }
+}
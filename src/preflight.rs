@@ -0,0 +1,77 @@
+use anyhow::{bail, Result};
+use std::fmt::Display;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::{error, info};
+
+use crate::config::Config;
+use crate::scraper::VestbeeScraper;
+use crate::SUMMARY_TARGET;
+
+/// Checks that a run can work at all: Chromium launches, the site answers
+/// and the (first) list page renders profile links. Logs pass/fail per
+/// check with its timing and fails if any check did.
+pub async fn run(config: &Config) -> Result<()> {
+    let mut failed = 0;
+    let list_url = config.list_urls().remove(0);
+
+    let (site, elapsed) = timed(reach_site(&list_url)).await;
+    failed += report("Site reachable", site, elapsed);
+
+    let (scraper, elapsed) = timed(VestbeeScraper::new(config)).await;
+    failed += report("Browser launches", scraper.as_ref().map(|_| "Chromium started"), elapsed);
+
+    match scraper {
+        Ok(scraper) => {
+            let (links, elapsed) = timed(list_has_links(&scraper, &list_url)).await;
+            failed += report("List page shows profile links", links, elapsed);
+            scraper.close().await?;
+        }
+        Err(_) => {
+            failed += report("List page shows profile links", Err::<String, _>("skipped, no browser"), Duration::ZERO);
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} of 3 preflight checks failed", failed);
+    }
+    info!(target: SUMMARY_TARGET, "All preflight checks passed");
+    Ok(())
+}
+
+async fn reach_site(url: &str) -> Result<String> {
+    let response = reqwest::get(url).await?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!("HTTP {}", status);
+    }
+    Ok(format!("HTTP {}", status))
+}
+
+async fn list_has_links(scraper: &VestbeeScraper, list_url: &str) -> Result<String> {
+    let count = scraper.list_page_link_count(list_url).await?;
+    if count == 0 {
+        bail!("no Details links found");
+    }
+    Ok(format!("{} profile links", count))
+}
+
+async fn timed<T>(future: impl Future<Output = T>) -> (T, Duration) {
+    let start = Instant::now();
+    let output = future.await;
+    (output, start.elapsed())
+}
+
+/// Logs one check's outcome, returning 1 if it failed.
+fn report(check: &str, outcome: Result<impl Display, impl Display>, elapsed: Duration) -> usize {
+    match outcome {
+        Ok(detail) => {
+            info!(target: SUMMARY_TARGET, "PASS {} ({}) in {:.1?}", check, detail, elapsed);
+            0
+        }
+        Err(e) => {
+            error!(target: SUMMARY_TARGET, "FAIL {}: {} in {:.1?}", check, e, elapsed);
+            1
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use comfy_table::Table;
+use std::io::{stdout, Write};
+
+use crate::models::Fund;
+
+/// Longest description shown in the preview, in characters.
+const DESCRIPTION_PREVIEW_LEN: usize = 60;
+
+/// Prints key fields of `funds` as a table on stdout, for `--preview`.
+pub fn print(funds: &[&Fund]) -> Result<()> {
+    let mut table = Table::new();
+    table.set_header(["Fund Name", "AUM", "Geographies", "Headquarters", "Status", "Description"]);
+    for fund in funds {
+        table.add_row([
+            fund.fund_name.as_str(),
+            &aum(fund),
+            fund.investment_geographies.as_str(),
+            fund.headquarters.as_str(),
+            fund.status.as_str(),
+            &truncate(&fund.fund_description, DESCRIPTION_PREVIEW_LEN),
+        ]);
+    }
+    writeln!(stdout().lock(), "{}", table)?;
+    Ok(())
+}
+
+/// The AUM followed by its currency code, as in "1500000000 EUR".
+fn aum(fund: &Fund) -> String {
+    let amount = fund.get_field("aum").unwrap_or_default();
+    match &fund.aum_currency {
+        Some(currency) if !amount.is_empty() => format!("{} {}", amount, currency),
+        _ => amount.into_owned(),
+    }
+}
+
+/// Cuts `text` to `max_len` characters, marking the cut with an ellipsis.
+fn truncate(text: &str, max_len: usize) -> String {
+    if text.chars().count() <= max_len {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max_len.saturating_sub(1)).collect();
+    format!("{}…", cut.trim_end())
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+/// Lowest rate backoff can go to: one fund every 20 seconds.
+const MIN_RPS: f64 = 0.05;
+
+/// Consecutive successes needed before the rate is raised a step.
+const INCREASE_AFTER: u32 = 5;
+
+/// Each increase adds this fraction of the cap.
+const INCREASE_STEP: f64 = 0.1;
+
+/// Each failure multiplies the rate by this.
+const DECREASE_FACTOR: f64 = 0.5;
+
+/// Spaces requests at most `rate` per second.
+pub struct RateLimiter {
+    interval: Duration,
+    next: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(rps: f64) -> Self {
+        Self {
+            interval: interval_for(rps),
+            next: Instant::now(),
+        }
+    }
+
+    /// Waits until the next request is allowed.
+    pub async fn acquire(&mut self) {
+        tokio::time::sleep_until(self.next).await;
+        self.next = Instant::now() + self.interval;
+    }
+
+    pub fn set_rate(&mut self, rps: f64) {
+        // Re-time the pending slot so a slowdown applies to the very next request
+        let last = self.next.checked_sub(self.interval).unwrap_or(self.next);
+        self.interval = interval_for(rps);
+        self.next = last + self.interval;
+    }
+}
+
+fn interval_for(rps: f64) -> Duration {
+    Duration::from_secs_f64(1.0 / rps)
+}
+
+/// A `RateLimiter` that adapts to how the site copes, like TCP congestion
+/// control (AIMD): every failure halves the rate, and each run of
+/// `INCREASE_AFTER` successes adds back a tenth of the cap, up to the cap.
+pub struct AdaptiveRateLimiter {
+    limiter: RateLimiter,
+    rate: f64,
+    max_rate: f64,
+    successes: u32,
+}
+
+impl AdaptiveRateLimiter {
+    /// Starts at `max_rps`, the rate the site is expected to handle.
+    pub fn new(max_rps: f64) -> Self {
+        Self {
+            limiter: RateLimiter::new(max_rps),
+            rate: max_rps,
+            max_rate: max_rps,
+            successes: 0,
+        }
+    }
+
+    pub async fn acquire(&mut self) {
+        self.limiter.acquire().await;
+    }
+
+    pub fn on_success(&mut self) {
+        self.successes += 1;
+        if self.successes < INCREASE_AFTER || self.rate >= self.max_rate {
+            return;
+        }
+        self.successes = 0;
+        self.set_rate((self.rate + self.max_rate * INCREASE_STEP).min(self.max_rate));
+        info!("Rate raised to {:.2} funds/s", self.rate);
+    }
+
+    pub fn on_failure(&mut self) {
+        self.successes = 0;
+        let rate = (self.rate * DECREASE_FACTOR).max(MIN_RPS.min(self.max_rate));
+        if rate < self.rate {
+            self.set_rate(rate);
+            warn!("Backing off to {:.2} funds/s after a failure", self.rate);
+        }
+    }
+
+    fn set_rate(&mut self, rate: f64) {
+        self.rate = rate;
+        self.limiter.set_rate(rate);
+    }
+}
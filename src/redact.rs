@@ -0,0 +1,65 @@
+use regex::{Captures, Regex};
+use std::io::{self, Write};
+use std::sync::LazyLock;
+use tracing_subscriber::fmt::MakeWriter;
+
+static EMAIL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b([A-Za-z0-9._%+-])[A-Za-z0-9._%+-]*@([A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)+)").unwrap());
+
+/// Only numbers in international form are recognized; bare digit runs in a
+/// log line are as likely to be counts or IDs.
+static PHONE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\+\d[\d ().-]{5,20}\d").unwrap());
+
+/// Wraps a log writer so emails and phone numbers are masked in every line
+/// written, for `--redact-pii`. Only the logs are affected; the data files
+/// keep the full values.
+pub struct Redacting<M> {
+    inner: M,
+}
+
+impl<M> Redacting<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for Redacting<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter {
+            inner: self.inner.make_writer(),
+        }
+    }
+}
+
+/// Masks each buffer before passing it on. The fmt layer writes an event
+/// in one call, so a value is never split across buffers.
+pub struct RedactingWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let text = String::from_utf8_lossy(buf);
+        self.inner.write_all(redact(&text).as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// `jane.doe@example.com` -> `j***@example.com`, `+44 20 7946 0958` -> `+**********58`.
+pub fn redact(text: &str) -> String {
+    let text = EMAIL.replace_all(text, |caps: &Captures| format!("{}***@{}", &caps[1], &caps[2]));
+    PHONE
+        .replace_all(&text, |caps: &Captures| {
+            let digits: Vec<char> = caps[0].chars().filter(char::is_ascii_digit).collect();
+            let kept = digits.len().saturating_sub(2);
+            let tail: String = digits[kept..].iter().collect();
+            format!("+{}{}", "*".repeat(kept), tail)
+        })
+        .into_owned()
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use tracing::{error, info};
+
+use crate::csv_writer::{read_funds, CsvExporter};
+use crate::json_writer::{raw_slug, read_raw};
+
+/// `reprocess-fields <input> <output>`: re-applies `Fund::normalize` to a
+/// CSV from an earlier run and writes the result, so cleaning improvements
+/// reach collected data without scraping again. With the run's `--emit-raw`
+/// directory, amounts are re-parsed from the text found on the page; without
+/// it they keep their stored values. Rows that can't be read are logged and
+/// left out.
+pub fn run(input: &str, output: &str, raw_dir: Option<&str>) -> Result<()> {
+    let csv = read_funds(input)?;
+    let raw = match raw_dir {
+        Some(dir) => {
+            let raw = read_raw(dir)?;
+            info!("Loaded raw text for {} funds from {}", raw.len(), dir);
+            raw
+        }
+        None => Default::default(),
+    };
+
+    let mut csv_writer = CsvExporter::new(output, &csv.columns, None, false, false)?;
+    csv_writer.write_header()?;
+
+    let mut written = 0;
+    let mut changed = 0;
+    let mut skipped = 0;
+    for (line, fund) in csv.rows {
+        let mut fund = match fund {
+            Ok(fund) => fund,
+            Err(e) => {
+                error!("Line {}: {}", line, e);
+                skipped += 1;
+                continue;
+            }
+        };
+
+        let before = fund.clone();
+        if let Some(fields) = raw.get(&raw_slug(&fund.fund_url)) {
+            fund.raw = fields.clone();
+        }
+        fund.normalize();
+        if csv.columns.iter().any(|column| before.get_field(column) != fund.get_field(column)) {
+            changed += 1;
+        }
+
+        csv_writer.write_fund(&fund)?;
+        written += 1;
+    }
+    csv_writer.finalize()?;
+
+    info!(
+        "Reprocessed {} funds from {} into {}: {} changed, {} unreadable rows skipped",
+        written, input, output, changed, skipped
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_writer::write_raw;
+    use crate::models::Fund;
+
+    /// Reprocesses `input` and returns the output CSV and the funds in it.
+    fn reprocess_csv(input: &str, raw_dir: Option<&str>) -> (String, Vec<Fund>) {
+        let dir = tempfile::tempdir().unwrap();
+        let input_path = dir.path().join("funds.csv");
+        let output_path = dir.path().join("cleaned.csv");
+        std::fs::write(&input_path, input).unwrap();
+
+        run(input_path.to_str().unwrap(), output_path.to_str().unwrap(), raw_dir).unwrap();
+        let funds = read_funds(output_path.to_str().unwrap())
+            .unwrap()
+            .rows
+            .into_iter()
+            .map(|(_, fund)| fund.unwrap())
+            .collect();
+        (std::fs::read_to_string(&output_path).unwrap(), funds)
+    }
+
+    fn reprocess(input: &str, raw_dir: Option<&str>) -> Vec<Fund> {
+        reprocess_csv(input, raw_dir).1
+    }
+
+    #[test]
+    fn normalizes_stored_fields() {
+        let funds = reprocess(
+            "fund_url,fund_name,investment_geographies,investment_stages,management_fee,tags\n\
+             https://www.vestbee.com/lp/acme-capital,\"  Acme   Capital \",\"CEE, Mars, dach\",\"pre-seed / Series-A\",2 %,\"Fintech; fintech\"\n",
+            None,
+        );
+        let fund = &funds[0];
+        assert_eq!(fund.fund_name, "Acme Capital");
+        assert_eq!(fund.investment_geographies, "CEE, DACH");
+        assert_eq!(fund.investment_stages, "Pre-Seed, Series A");
+        assert_eq!(fund.management_fee, "2%");
+        assert_eq!(fund.tags, ["Fintech"]);
+    }
+
+    #[test]
+    fn reparses_amounts_from_raw_text() {
+        let raw_dir = tempfile::tempdir().unwrap();
+        let mut fund = Fund::new();
+        fund.fund_url = "https://www.vestbee.com/lp/acme-capital".to_string();
+        fund.raw.insert("aum".to_string(), "€250,000".to_string());
+        fund.raw.insert("fund_size".to_string(), "$40M".to_string());
+        write_raw(raw_dir.path().to_str().unwrap(), 1, &fund).unwrap();
+
+        let input = "fund_url,aum,aum_currency,fund_size,fund_size_currency\n\
+                     https://www.vestbee.com/lp/acme-capital,250,,,\n";
+        let funds = reprocess(input, raw_dir.path().to_str());
+        assert_eq!(funds[0].aum_value, Some(250_000.0));
+        assert_eq!(funds[0].aum_currency.as_deref(), Some("EUR"));
+        assert_eq!(funds[0].fund_size, Some(40_000_000.0));
+        assert_eq!(funds[0].fund_size_currency.as_deref(), Some("USD"));
+
+        // Without the raw text the stored amounts are kept
+        let funds = reprocess(input, None);
+        assert_eq!(funds[0].aum_value, Some(250.0));
+    }
+
+    #[test]
+    fn reprocessing_twice_changes_nothing_more() {
+        let input = "fund_url,fund_name,investment_geographies\n\
+                     https://www.vestbee.com/lp/acme-capital,\" Acme  Capital\",\"CEE, Mars\"\n";
+        let (once, _) = reprocess_csv(input, None);
+        let (twice, _) = reprocess_csv(&once, None);
+        assert_ne!(once, input);
+        assert_eq!(twice, once);
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+
+/// Minimum Jaro-Winkler similarity for a portfolio name to be mapped onto a
+/// roster entry, e.g. "Open AI vetures" onto "OpenAI".
+const MATCH_THRESHOLD: f64 = 0.88;
+
+/// Canonical portfolio company names, used to make portfolios joinable across funds.
+pub struct PortfolioRoster {
+    /// `(match key, canonical name)` pairs.
+    entries: Vec<(String, String)>,
+    /// Portfolio names seen without a close enough roster entry.
+    unmatched: BTreeSet<String>,
+}
+
+impl PortfolioRoster {
+    /// Reads one canonical name per line; blank lines and `#` comments are skipped.
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read portfolio roster {}", path))?;
+
+        let entries = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|name| (match_key(name), name.to_string()))
+            .collect();
+
+        Ok(Self {
+            entries,
+            unmatched: BTreeSet::new(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Rewrites a `;`-separated portfolio list with canonical names. Names
+    /// without a match are kept as scraped and remembered for `unmatched`.
+    pub fn canonicalize_portfolio(&mut self, portfolio: &str) -> String {
+        portfolio
+            .split(';')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| match self.canonical_name(name) {
+                Some(canonical) => canonical.to_string(),
+                None => {
+                    self.unmatched.insert(name.to_string());
+                    name.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// Names that didn't match any roster entry, sorted, for roster expansion.
+    pub fn unmatched(&self) -> &BTreeSet<String> {
+        &self.unmatched
+    }
+
+    fn canonical_name(&self, name: &str) -> Option<&str> {
+        let key = match_key(name);
+        if let Some((_, canonical)) = self.entries.iter().find(|(entry, _)| *entry == key) {
+            return Some(canonical);
+        }
+
+        self.entries
+            .iter()
+            .map(|(entry, canonical)| (strsim::jaro_winkler(entry, &key), canonical))
+            .filter(|(score, _)| *score >= MATCH_THRESHOLD)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, canonical)| canonical.as_str())
+    }
+}
+
+/// Lowercase alphanumerics only, so spacing and punctuation don't affect matching.
+fn match_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
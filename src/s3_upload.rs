@@ -0,0 +1,71 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Uploads the given output files to `s3://<bucket>/<prefix>/<file name>`.
+/// Credentials come from the standard AWS chain (environment, profile,
+/// instance role). Local files are left in place either way, so a failed
+/// upload can simply be retried by hand.
+#[cfg(feature = "s3")]
+pub async fn upload(bucket: &str, prefix: &str, paths: &[PathBuf]) -> Result<()> {
+    use anyhow::Context;
+    use aws_sdk_s3::primitives::ByteStream;
+    use tracing::info;
+
+    let sdk_config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+    let client = aws_sdk_s3::Client::new(&sdk_config);
+
+    for path in paths {
+        let key = object_key(prefix, path);
+        let body = ByteStream::from_path(path)
+            .await
+            .with_context(|| format!("Failed to read {} for upload", path.display()))?;
+        client
+            .put_object()
+            .bucket(bucket)
+            .key(&key)
+            .content_type(content_type(path))
+            .body(body)
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to upload {} to s3://{}/{}; the local files are kept",
+                    path.display(),
+                    bucket,
+                    key
+                )
+            })?;
+        info!("Uploaded {} to s3://{}/{}", path.display(), bucket, key);
+    }
+    Ok(())
+}
+
+/// Stand-in for builds without S3 support; `Config` already rejects
+/// `--s3-bucket` there, so this is never reached.
+#[cfg(not(feature = "s3"))]
+pub async fn upload(_bucket: &str, _prefix: &str, _paths: &[PathBuf]) -> Result<()> {
+    anyhow::bail!("S3 upload requires building with --features s3")
+}
+
+/// `prefix` with the file name appended, e.g. `exports/daily` + `vestbee_funds.csv`.
+#[cfg_attr(not(feature = "s3"), allow(dead_code))]
+fn object_key(prefix: &str, path: &Path) -> String {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let prefix = prefix.trim_matches('/');
+    if prefix.is_empty() {
+        name.into_owned()
+    } else {
+        format!("{}/{}", prefix, name)
+    }
+}
+
+#[cfg_attr(not(feature = "s3"), allow(dead_code))]
+fn content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("csv") => "text/csv",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        Some("json") => "application/json",
+        Some("gz") => "application/gzip",
+        _ => "application/octet-stream",
+    }
+}
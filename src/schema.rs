@@ -0,0 +1,28 @@
+use anyhow::Result;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::models::Fund;
+use crate::output::write_atomically;
+
+/// `schema [path]`: writes the JSON Schema of a fund record, derived from
+/// `Fund` so it always matches what the scraper emits. Printed to stdout
+/// without a path.
+pub fn run(path: Option<&str>) -> Result<()> {
+    let schema = serde_json::to_string_pretty(&schemars::schema_for!(Fund))?;
+
+    match path {
+        Some(path) => write_atomically(Path::new(path), |tmp_path| {
+            let mut writer = BufWriter::new(File::create(tmp_path)?);
+            writeln!(writer, "{}", schema)?;
+            writer.flush()?;
+            Ok(())
+        }),
+        None => {
+            // An error rather than a panic when the reader goes away, as with `| head`
+            writeln!(std::io::stdout().lock(), "{}", schema)?;
+            Ok(())
+        }
+    }
+}
@@ -1,184 +1,377 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
 use chromiumoxide::browser::{Browser, BrowserConfig};
-use futures::StreamExt;
-use std::time::Duration;
-use tracing::{error, info, warn};
+use chromiumoxide::cdp::browser_protocol::emulation::SetLocaleOverrideParams;
+use chromiumoxide::cdp::browser_protocol::network::{
+    EventResponseReceived, Headers, ResourceType, SetExtraHttpHeadersParams,
+};
+use chromiumoxide::error::CdpError;
+use chromiumoxide::Page;
+use futures::{FutureExt, StreamExt};
+use std::collections::HashSet;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::{RwLock, Semaphore};
+use tracing::{debug, error, info, warn};
 
-use crate::models::Fund;
+use crate::checkpoint::DiscoveryCheckpoint;
+use crate::config::Config;
+use crate::extractors::{
+    default_extractors, extract_structured_data, js_with_arg, strategy_confidence, Extracted, FieldExtractor, Profile,
+};
+use crate::models::{canonical_url, DiscoveredUrl, Fund, FundBuilder, FIELD_NAMES};
+
+/// The list page funds are discovered from.
+pub const LP_LIST_URL: &str = "https://www.vestbee.com/lp-list";
+
+/// Per-page log of discovered URLs in the output directory, for `--resume-discovery`.
+const DISCOVERY_CHECKPOINT: &str = "fund_urls.partial.jsonl";
+
+/// Query parameter selecting a list page when navigating to one directly.
+const PAGE_QUERY_PARAM: &str = "page";
+
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest wait for the navigation's response event once `goto` returned.
+const RESPONSE_EVENT_WAIT: Duration = Duration::from_secs(1);
+
+/// How often a loading page is checked for the content being waited on.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Element a profile shows once it has rendered: the fund name heading.
+const PROFILE_READY_SELECTOR: &str = "h1";
+
+/// Longest wait for an extractor's `wait_for` selector. Profiles without
+/// that section pay it in full, so it is kept short.
+const SECTION_WAIT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Messages (regex sources, case-insensitive) a list page shows when it has
+/// nothing to list, as opposed to selectors that no longer match.
+const EMPTY_STATE_PATTERNS: &[&str] = &[
+    r"^no (funds|results|investors|lps|matches|items|records)( were)?( found| available| to (show|display))?[.!]?$",
+    r"^nothing (found|to show|to display)[.!]?$",
+    r"^0 (funds|results|investors)( found)?[.!]?$",
+];
+
+/// What discovery found on the list page.
+pub enum Discovery {
+    Urls(Vec<DiscoveredUrl>),
+    /// The site explicitly reports zero results, with the message it shows.
+    ReportedEmpty(String),
+}
 
 pub struct VestbeeScraper {
-    browser: Browser,
-    base_url: String,
+    browser: Arc<RwLock<Browser>>,
+    healthy: Arc<AtomicBool>,
+    generation: Arc<AtomicU64>,
+    /// Retries made by `scrape_with_retry` across the whole run.
+    retries: AtomicU64,
+    headless: bool,
+    chrome_args: Vec<String>,
+    chrome_path: Option<PathBuf>,
+    locale: String,
+    /// Sent with every page alongside `Accept-Language`.
+    extra_headers: Vec<(String, String)>,
+    details_labels: Vec<String>,
+    /// Bounds profile pages open at once, `--max-open-pages`.
+    open_pages: Semaphore,
+    /// Bounds profiles being opened and navigated at once, separately from
+    /// the pages open for extraction, `--max-concurrent-navigations`.
+    navigations: Semaphore,
+    maintenance_markers: Vec<String>,
+    resume_discovery: bool,
+    /// Where the discovery checkpoint is kept.
+    out_dir: String,
+    list_load_timeout: Duration,
+    /// Longest a profile may take to open and render, `--nav-timeout-secs`.
+    nav_timeout: Duration,
+    /// Longest wait for a profile's name heading, or for a list page to
+    /// change after clicking "Next".
+    page_wait: Duration,
+    extractors: Vec<Box<dyn FieldExtractor>>,
 }
 
 impl VestbeeScraper {
-    pub async fn new(headless: bool) -> Result<Self> {
+    pub async fn new(config: &Config) -> Result<Self> {
         info!("Initializing browser");
-        
-        let mut config = BrowserConfig::builder();
-        if !headless {
-            config = config.with_head();
+
+        let healthy = Arc::new(AtomicBool::new(true));
+        let generation = Arc::new(AtomicU64::new(0));
+        let chrome_args = config.chrome_args();
+        if !chrome_args.is_empty() {
+            info!("Extra Chromium args: {}", chrome_args.join(" "));
         }
-        config = config.window_size(1920, 1080);
-        config = config.viewport(None);
-        
-        let browser_config = config.build()
-            .map_err(|e| anyhow::anyhow!("Failed to build browser config: {}", e))?;
-        
-        let (browser, mut handler) = Browser::launch(browser_config)
-            .await
-            .context("Failed to launch browser")?;
-
-        tokio::spawn(async move {
-            while let Some(h) = handler.next().await {
-                if let Err(e) = h {
-                    error!("Browser handler error: {:?}", e);
-                }
+        let chrome_path = match &config.chrome_path {
+            Some(path) => {
+                let path = validate_chrome_path(path)?;
+                info!("Using Chromium at {}", path.display());
+                Some(path)
             }
-        });
+            None => None,
+        };
+        let extra_headers = config.extra_headers();
+        for (name, value) in &extra_headers {
+            info!("Sending header {}: {}", name, value);
+        }
+        let browser = launch_browser(
+            config.headless,
+            &chrome_args,
+            chrome_path.as_deref(),
+            healthy.clone(),
+            generation.clone(),
+        )
+        .await?;
+        let browser = Arc::new(RwLock::new(browser));
+
+        spawn_health_check(
+            Arc::downgrade(&browser),
+            healthy.clone(),
+            Duration::from_secs(config.health_check_secs),
+        );
 
         Ok(Self {
             browser,
-            base_url: "https://www.vestbee.com/lp-list".to_string(),
+            healthy,
+            generation,
+            retries: AtomicU64::new(0),
+            headless: config.headless,
+            chrome_args,
+            chrome_path,
+            locale: config.locale.clone(),
+            extra_headers,
+            details_labels: config.details_labels(),
+            open_pages: Semaphore::new(config.max_open_pages),
+            navigations: Semaphore::new(config.max_concurrent_navigations),
+            maintenance_markers: config.maintenance_markers(),
+            resume_discovery: config.resume_discovery,
+            out_dir: config.out_dir.clone(),
+            list_load_timeout: Duration::from_secs(config.list_load_timeout_secs),
+            nav_timeout: Duration::from_secs(config.nav_timeout_secs),
+            page_wait: Duration::from_millis(config.page_wait_ms),
+            extractors: default_extractors(config),
         })
     }
 
-    pub async fn get_fund_urls(&self) -> Result<Vec<String>> {
-        info!("Navigating to LP list page");
-        let page = self.browser.new_page(&self.base_url).await?;
-        
-        tokio::time::sleep(Duration::from_secs(3)).await;
-        
-        let mut all_fund_urls = Vec::new();
-        let mut page_number = 1;
-        let mut has_next_page = true;
-        
-        while has_next_page {
+    /// Total retries so far, for the run-wide `--global-retry-budget`.
+    pub fn retry_count(&self) -> u64 {
+        self.retries.load(Ordering::SeqCst)
+    }
+
+    /// False once the browser's handler has stopped or a health check failed.
+    pub fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::SeqCst)
+    }
+
+    /// Replaces a dead browser with a freshly launched one.
+    pub async fn relaunch(&self) -> Result<()> {
+        let mut browser = self.browser.write().await;
+        // Another worker saw the same failure and relaunched while this one waited
+        if self.is_healthy() {
+            return Ok(());
+        }
+        warn!("Relaunching browser");
+
+        // Retire the old browser's handler so its shutdown doesn't mark the new one unhealthy
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        if let Err(e) = browser.close().await {
+            warn!("Failed to close unhealthy browser: {}", e);
+        }
+
+        *browser = launch_browser(
+            self.headless,
+            &self.chrome_args,
+            self.chrome_path.as_deref(),
+            self.healthy.clone(),
+            self.generation.clone(),
+        )
+        .await?;
+        self.healthy.store(true, Ordering::SeqCst);
+        info!("Browser relaunched");
+        Ok(())
+    }
+
+    /// Opens a tab pinned to the configured locale, with the politeness
+    /// headers set, and navigates it to `url`.
+    async fn open_page(&self, url: &str) -> Result<Page> {
+        let page = self.new_tab().await?;
+        if let Err(e) = page.goto(url).await {
+            close_page(page, url).await;
+            return Err(e.into());
+        }
+        Ok(page)
+    }
+
+    /// Navigates `page` to the profile at `url` and waits for it to render.
+    /// Fails with an `HttpStatusError` when the server answers with an error
+    /// status, so retries can tell a missing profile from an overloaded server.
+    async fn load_profile(&self, page: &Page, url: &str) -> Result<()> {
+        let mut responses = page.event_listener::<EventResponseReceived>().await?;
+        page.goto(url).await?;
+
+        // The document's response was dispatched before `goto` returned
+        let mut document = None;
+        while let Ok(Some(event)) = tokio::time::timeout(RESPONSE_EVENT_WAIT, responses.next()).await {
+            if event.r#type == ResourceType::Document {
+                document = Some(event);
+                break;
+            }
+        }
+
+        match document {
+            Some(event) => check_status(&event, url)?,
+            None => debug!("No document response seen for {}, assuming success", url),
+        }
+
+        // Profiles render client-side; once the name heading is in, so is the rest
+        if !wait_for_selector(page, PROFILE_READY_SELECTOR, self.page_wait).await? {
+            debug!("No {} on {} after {:?}, extracting anyway", PROFILE_READY_SELECTOR, url, self.page_wait);
+        }
+        Ok(())
+    }
+
+    async fn new_tab(&self) -> Result<Page> {
+        let page = self.browser.read().await.new_page("about:blank").await?;
+        if let Err(e) = self.configure_tab(&page).await {
+            close_page(page, "about:blank").await;
+            return Err(e);
+        }
+        Ok(page)
+    }
+
+    /// Pins a fresh tab to the configured locale and sets the politeness
+    /// headers, before it navigates anywhere.
+    async fn configure_tab(&self, page: &Page) -> Result<()> {
+        let mut headers = serde_json::Map::new();
+        headers.insert("Accept-Language".to_string(), self.locale.clone().into());
+        for (name, value) in &self.extra_headers {
+            headers.insert(name.clone(), value.clone().into());
+        }
+        page.execute(SetExtraHttpHeadersParams::new(Headers::new(serde_json::Value::Object(headers))))
+            .await?;
+        // The emulation domain takes ICU style locales: en_US rather than en-US
+        page.execute(
+            SetLocaleOverrideParams::builder()
+                .locale(self.locale.replace('-', "_"))
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_fund_urls(&self, list_url: &str) -> Result<Discovery> {
+        let checkpoint_path = Path::new(&self.out_dir).join(checkpoint_name(list_url));
+        let checkpoint = DiscoveryCheckpoint::open(&checkpoint_path.to_string_lossy(), self.resume_discovery)?;
+        // Membership checks against the discovery-ordered Vec would make
+        // discovery quadratic
+        let seen: HashSet<String> = checkpoint.urls().into_iter().collect();
+
+        let (page, page_number, fund_urls) = match checkpoint.last_page() {
+            Some(last_page) => self.resume_list_page(list_url, last_page + 1, &seen).await?,
+            None => {
+                info!("Navigating to list page {}", list_url);
+                let page = self.open_page(list_url).await?;
+                match self.wait_for_list(&page).await {
+                    Ok(fund_urls) => (page, 1, fund_urls),
+                    Err(e) => {
+                        close_page(page, list_url).await;
+                        return Err(e);
+                    }
+                }
+            }
+        };
+
+        // The list tab stays open through pagination; close it however that ends
+        let discovery = self
+            .paginate(list_url, &page, page_number, fund_urls, checkpoint, seen)
+            .await;
+        close_page(page, list_url).await;
+        discovery
+    }
+
+    /// Walks the list from `page_number`, whose links are `fund_urls`,
+    /// onwards, adding the URLs not `seen` yet and checkpointing each page.
+    async fn paginate(
+        &self,
+        list_url: &str,
+        page: &Page,
+        mut page_number: usize,
+        mut fund_urls: Vec<String>,
+        mut checkpoint: DiscoveryCheckpoint,
+        mut seen: HashSet<String>,
+    ) -> Result<Discovery> {
+        let mut all_fund_urls = checkpoint.urls();
+        // `seen` includes the checkpoint, so a resume that restarted from
+        // page 1 would find nothing new on it; wrap-around is judged against
+        // the pages walked in this pass instead
+        let mut this_pass: HashSet<String> = HashSet::new();
+
+        // Every profile would come back empty, so there is no point going on
+        if fund_urls.is_empty() {
+            if let Some(marker) = self.maintenance_marker(page).await? {
+                bail!(
+                    "Site in maintenance: the list page shows \"{}\". Try again once it is back up",
+                    marker
+                );
+            }
+        }
+
+        loop {
             info!("Scraping page {}", page_number);
-            
-            // Get fund URLs from current page
-            let fund_urls = page
-                .evaluate(
-                    r#"
-                    Array.from(document.querySelectorAll('a, button'))
-                        .filter(el => el.innerText && el.innerText.includes('Details'))
-                        .map(el => {
-                            if (el.tagName === 'A' && el.href) {
-                                return el.href;
-                            } else if (el.onclick) {
-                                return el.getAttribute('data-href') || el.getAttribute('href') || '';
-                            }
-                            const parent = el.closest('a');
-                            if (parent && parent.href) {
-                                return parent.href;
-                            }
-                            const card = el.closest('[data-href], [href]');
-                            if (card) {
-                                return card.getAttribute('data-href') || card.getAttribute('href') || '';
-                            }
-                            return '';
-                        })
-                        .filter(url => url && url.length > 0)
-                        .map(url => {
-                            if (url.startsWith('http')) return url;
-                            if (url.startsWith('/')) return window.location.origin + url;
-                            return window.location.origin + '/' + url;
-                        })
-                        .filter(url => !url.includes('undefined'));
-                    "#,
-                )
-                .await?
-                .into_value::<Vec<String>>()?;
-            
             info!("Found {} funds on page {}", fund_urls.len(), page_number);
             
             // Add unique URLs to our collection
-            for url in fund_urls {
-                if !all_fund_urls.contains(&url) {
-                    all_fund_urls.push(url);
+            let mut new_count = 0;
+            for url in &fund_urls {
+                if seen.insert(url.clone()) {
+                    all_fund_urls.push(url.clone());
+                }
+                if this_pass.insert(url.clone()) {
+                    new_count += 1;
                 }
             }
-            
-            // Check if there's a next page and click it
-            has_next_page = page
-                .evaluate(
-                    r#"
-                    (() => {
-                        // First try to find and click "Next" button
-                        const nextButtons = Array.from(document.querySelectorAll('a, button'))
-                            .filter(el => {
-                                const text = (el.textContent || '').toLowerCase();
-                                const ariaLabel = (el.getAttribute('aria-label') || '').toLowerCase();
-                                return text === 'next' || 
-                                       text.includes('next') || 
-                                       ariaLabel.includes('next') ||
-                                       text === '→' ||
-                                       text === '>';
-                            });
-                        
-                        for (const btn of nextButtons) {
-                            // Check if next button is not disabled
-                            if (!btn.disabled && !btn.classList.contains('disabled')) {
-                                btn.click();
-                                return true;
-                            }
-                        }
-                        
-                        // Alternative: Look for numbered pagination
-                        const currentPage = document.querySelector('.pagination .active, [aria-current="page"]');
-                        if (currentPage) {
-                            const currentPageNum = parseInt(currentPage.textContent);
-                            // Find link with next page number
-                            const allLinks = Array.from(document.querySelectorAll('a'));
-                            const nextPageLink = allLinks.find(link => link.textContent.trim() === String(currentPageNum + 1));
-                            if (nextPageLink) {
-                                nextPageLink.click();
-                                return true;
-                            }
-                        }
-                        
-                        // Alternative: Look for page number links
-                        const pageLinks = Array.from(document.querySelectorAll('a'))
-                            .filter(el => {
-                                const text = el.textContent || '';
-                                return /^\d+$/.test(text.trim());
-                            })
-                            .sort((a, b) => parseInt(a.textContent) - parseInt(b.textContent));
-                        
-                        // Find current page and click next
-                        for (let i = 0; i < pageLinks.length - 1; i++) {
-                            if (pageLinks[i].classList.contains('active') || 
-                                pageLinks[i].getAttribute('aria-current') === 'page') {
-                                pageLinks[i + 1].click();
-                                return true;
-                            }
-                        }
-                        
-                        return false;
-                    })()
-                    "#,
-                )
-                .await?
-                .into_value::<bool>()?;
-            
-            if has_next_page {
-                info!("Navigating to page {}", page_number + 1);
-                tokio::time::sleep(Duration::from_secs(3)).await;
-                page_number += 1;
-                
-                // Safety check - don't scrape more than 100 pages
-                if page_number > 100 {
-                    warn!("Reached maximum page limit of 100, stopping pagination");
+            checkpoint.record(page_number, &fund_urls)?;
+
+            // A page with nothing new to this pass means the pager wrapped
+            // around to page 1 or repeated a page, whatever state its buttons
+            // are in
+            if new_count == 0 {
+                info!("Page {} had no new funds, finished pagination", page_number);
+                break;
+            }
+
+            // Check if there's a next page and move to it
+            match self.advance_page(page, &fund_urls).await? {
+                Some(next_urls) => {
+                    fund_urls = next_urls;
+                    page_number += 1;
+                    info!("Navigated to page {}", page_number);
+                    
+                    // Safety check - don't scrape more than 100 pages
+                    if page_number > 100 {
+                        warn!("Reached maximum page limit of 100, stopping pagination");
+                        break;
+                    }
+                }
+                None => {
+                    info!("No more pages found, finished pagination");
                     break;
                 }
-            } else {
-                info!("No more pages found, finished pagination");
             }
         }
+        checkpoint.finish()?;
         
         let fund_urls = all_fund_urls;
 
         if fund_urls.is_empty() {
+            if let Some(message) = self.empty_state_message(page).await? {
+                info!("Site reports zero results: \"{}\"", message);
+                return Ok(Discovery::ReportedEmpty(message));
+            }
+
             warn!("No fund URLs found, trying alternative selectors");
             
             let alternative_urls = page
@@ -204,418 +397,920 @@ impl VestbeeScraper {
             
             if !alternative_urls.is_empty() {
                 info!("Found {} URLs using alternative selectors", alternative_urls.len());
-                return Ok(alternative_urls);
+                return Ok(Discovery::Urls(DiscoveredUrl::index_all(alternative_urls, list_url)));
             }
         }
 
         info!("Found {} fund URLs", fund_urls.len());
-        Ok(fund_urls)
+        Ok(Discovery::Urls(DiscoveredUrl::index_all(fund_urls, list_url)))
     }
 
-    pub async fn scrape_fund_details(&self, url: &str) -> Result<Fund> {
-        info!("Scraping fund details from: {}", url);
-        let page = self.browser.new_page(url).await?;
-        
-        tokio::time::sleep(Duration::from_secs(3)).await;
-        
-        let mut fund = Fund::new();
-        fund.fund_url = url.to_string();
+    /// Opens list page `page_number` directly by URL to continue an
+    /// interrupted discovery. If the site ignores the page parameter and
+    /// shows only URLs already found, discovery restarts from page 1.
+    async fn resume_list_page(
+        &self,
+        list_url: &str,
+        page_number: usize,
+        known_urls: &HashSet<String>,
+    ) -> Result<(Page, usize, Vec<String>)> {
+        let mut url = reqwest::Url::parse(list_url)?;
+        url.query_pairs_mut()
+            .append_pair(PAGE_QUERY_PARAM, &page_number.to_string());
+        info!(
+            "Resuming discovery at page {} ({} URLs already found)",
+            page_number,
+            known_urls.len()
+        );
 
-        let fund_name = page
-            .evaluate(
+        let page = self.open_page(url.as_str()).await?;
+        match self.load_resumed_page(list_url, &page, page_number, known_urls).await {
+            Ok((page_number, fund_urls)) => Ok((page, page_number, fund_urls)),
+            Err(e) => {
+                close_page(page, list_url).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn load_resumed_page(
+        &self,
+        list_url: &str,
+        page: &Page,
+        page_number: usize,
+        known_urls: &HashSet<String>,
+    ) -> Result<(usize, Vec<String>)> {
+        let fund_urls = self.wait_for_list(page).await?;
+
+        if !fund_urls.is_empty() && fund_urls.iter().all(|url| known_urls.contains(url)) {
+            warn!("List page {} only shows known funds, restarting discovery from page 1", page_number);
+            page.goto(list_url).await?;
+            let fund_urls = self.wait_for_list(page).await?;
+            return Ok((1, fund_urls));
+        }
+
+        Ok((page_number, fund_urls))
+    }
+
+    /// The first maintenance marker in the page's title or text, if any.
+    /// Only consulted when the list is empty, so a fund that mentions
+    /// maintenance can't stop a healthy run.
+    async fn maintenance_marker(&self, page: &Page) -> Result<Option<String>> {
+        let marker = page
+            .evaluate(js_with_arg(
                 r#"
-                (() => {
-                    const selectors = ['h1', '.fund-name', '.company-name', '.title', '[class*="name"]'];
-                    for (const selector of selectors) {
-                        const el = document.querySelector(selector);
-                        if (el && el.textContent) {
-                            return el.textContent.trim();
-                        }
-                    }
-                    return '';
-                })()
+                ((markers) => {
+                    const text = (document.title + '\n' + (document.body ? document.body.innerText : '')).toLowerCase();
+                    return markers.find(marker => text.includes(marker.toLowerCase())) || null;
+                })(__ARG__)
                 "#,
-            )
+                &self.maintenance_markers,
+            )?)
             .await?
-            .into_value::<String>()?;
-        fund.fund_name = fund_name;
+            .into_value::<Option<String>>()?;
 
-        let geographies = page
-            .evaluate(
+        Ok(marker)
+    }
+
+    /// The page's "no results" message, if it shows one.
+    async fn empty_state_message(&self, page: &Page) -> Result<Option<String>> {
+        let message = page
+            .evaluate(js_with_arg(
                 r#"
-                (() => {
-                    // Define valid geographic regions - only actual location names
-                    const validGeos = new Set([
-                        'Global', 'Europe', 'Asia', 'Africa', 'America', 'Americas',
-                        'North America', 'South America', 'Latin America',
-                        'USA', 'US', 'United States', 'UK', 'United Kingdom', 
-                        'Germany', 'France', 'Spain', 'Italy', 'Poland', 
-                        'Ireland', 'Netherlands', 'Belgium', 'Switzerland', 
-                        'Austria', 'Sweden', 'Norway', 'Denmark', 'Finland',
-                        'Portugal', 'Greece', 'Czech Republic', 'Hungary',
-                        'Romania', 'Bulgaria', 'Croatia', 'Serbia', 'Slovenia',
-                        'Estonia', 'Latvia', 'Lithuania', 'Luxembourg',
-                        'Canada', 'Mexico', 'Brazil', 'Argentina', 'Chile',
-                        'China', 'Japan', 'India', 'Singapore', 'Australia',
-                        'Israel', 'Turkey', 'Russia', 'Ukraine',
-                        'EMEA', 'APAC', 'LATAM', 'NAMER', 'MENA', 
-                        'CEE', 'DACH', 'Nordics', 'Benelux',
-                        'Central Europe', 'Eastern Europe', 'Western Europe',
-                        'Northern Europe', 'Southern Europe'
-                    ]);
-                    
-                    const foundGeos = new Set();
-                    
-                    // Look for geography section specifically
-                    const allElements = Array.from(document.querySelectorAll('*'));
-                    
-                    for (const el of allElements) {
-                        const text = (el.textContent || '').trim();
-                        
-                        // Skip long text blocks and URLs
-                        if (text.length > 200 || text.includes('http') || text.includes('www.')) {
-                            continue;
-                        }
-                        
-                        // Look for labeled geography sections
-                        if (text.includes('Investment geography') || 
-                            text.includes('Geography') || 
-                            text.includes('Regions')) {
-                            
-                            // Split by common delimiters
-                            const parts = text.split(/[,;:\/\n]/);
-                            
-                            for (const part of parts) {
-                                const cleaned = part.trim();
-                                
-                                // Check if it's a valid geography
-                                for (const geo of validGeos) {
-                                    if (cleaned === geo || 
-                                        (cleaned.toLowerCase() === geo.toLowerCase())) {
-                                        foundGeos.add(geo);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    
-                    // Also check for standalone geography mentions in small text blocks
-                    for (const el of allElements) {
-                        const text = (el.textContent || '').trim();
-                        
-                        // Only check small text blocks
-                        if (text.length > 50 || text.includes('http')) {
+                ((patterns) => {
+                    const regexes = patterns.map(p => new RegExp(p, 'i'));
+                    for (const el of document.querySelectorAll('body *')) {
+                        // Only elements showing their own short text, not whole sections
+                        if (el.children.length > 0 || el.offsetParent === null) {
                             continue;
                         }
-                        
-                        // Direct match with valid geographies
-                        for (const geo of validGeos) {
-                            if (text === geo || 
-                                (text.toLowerCase() === geo.toLowerCase() && text.length === geo.length)) {
-                                foundGeos.add(geo);
-                            }
+                        const text = (el.textContent || '').trim().replace(/\s+/g, ' ');
+                        if (text && text.length <= 60 && regexes.some(r => r.test(text))) {
+                            return text;
                         }
                     }
-                    
-                    // Return unique geographies, excluding any with special characters or URLs
-                    return Array.from(foundGeos)
-                        .filter(g => !g.includes('/') && !g.includes('.') && !g.includes('Type'))
-                        .join(', ');
-                })()
+                    return '';
+                })(__ARG__)
                 "#,
-            )
+                &EMPTY_STATE_PATTERNS,
+            )?)
             .await?
             .into_value::<String>()?;
-        fund.investment_geographies = geographies;
 
-        // Extract AUM and convert to US number format
-        let aum = page
-            .evaluate(
+        Ok(Some(message).filter(|message| !message.is_empty()))
+    }
+
+    /// Number of profile links a list page renders, for `--preflight`.
+    pub async fn list_page_link_count(&self, list_url: &str) -> Result<usize> {
+        let page = self.open_page(list_url).await?;
+        let count = self.wait_for_list(&page).await;
+        close_page(page, list_url).await;
+        Ok(count?.len())
+    }
+
+    /// Waits for a freshly opened list page to render its fund links, up to
+    /// `--list-load-timeout`, and returns them. Stops early when the page
+    /// shows an empty-state message. Empty if the timeout runs out.
+    async fn wait_for_list(&self, page: &Page) -> Result<Vec<String>> {
+        let started = Instant::now();
+        loop {
+            let fund_urls = self.collect_page_urls(page).await?;
+            if !fund_urls.is_empty() {
+                info!("List page loaded in {:.1}s", started.elapsed().as_secs_f64());
+                return Ok(fund_urls);
+            }
+            if self.empty_state_message(page).await?.is_some() {
+                info!("List page reported no results after {:.1}s", started.elapsed().as_secs_f64());
+                return Ok(fund_urls);
+            }
+            if started.elapsed() >= self.list_load_timeout {
+                warn!(
+                    "No fund links on the list page after {:.1}s (--list-load-timeout)",
+                    started.elapsed().as_secs_f64()
+                );
+                return Ok(fund_urls);
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Collects the profile URLs behind the "Details" buttons on the current list page.
+    ///
+    /// A button can point at its profile in several ways, sometimes through
+    /// a tracking redirect as well, so one URL is picked per button in this
+    /// order of precedence:
+    ///
+    /// 1. the `href` of the `<a>` the label is on or inside,
+    /// 2. a `data-href` on the element or its card,
+    /// 3. any other `href` attribute on an enclosing element,
+    ///
+    /// with every same-origin URL preferred over any external one.
+    async fn collect_page_urls(&self, page: &Page) -> Result<Vec<String>> {
+        let fund_urls = page
+            .evaluate(js_with_arg(
                 r#"
-                (() => {
-                    // Look for AUM in various formats
-                    const texts = Array.from(document.querySelectorAll('*')).map(el => el.textContent || '');
-                    const aumPatterns = [
-                        /AUM[:\s]*([€$£¥]?\s*[\d,.\+]+(?:[.,]\d+)?\+?\s*[TBMK](?:rillion|illion)?\s*(?:EUR|USD|GBP)?)/i,
-                        /Assets\s*Under\s*Management[:\s]*([€$£¥]?\s*[\d,.\+]+(?:[.,]\d+)?\+?\s*[TBMK](?:rillion|illion)?\s*(?:EUR|USD|GBP)?)/i
-                    ];
-                    
-                    for (const text of texts) {
-                        for (const pattern of aumPatterns) {
-                            const match = text.match(pattern);
-                            if (match && match[1]) {
-                                let aumValue = match[1].trim();
-                                
-                                // Remove + sign if present
-                                aumValue = aumValue.replace(/\+/g, '');
-                                
-                                // Parse the number and convert to euros
-                                // Remove currency symbols and text
-                                aumValue = aumValue.replace(/[€$£¥]/g, '').replace(/EUR|USD|GBP/gi, '').trim();
-                                
-                                // Determine the multiplier (convert to base euros)
-                                let multiplier = 1;
-                                if (aumValue.toLowerCase().includes('t')) {
-                                    multiplier = 1000000000000; // trillion
-                                    aumValue = aumValue.replace(/t(?:rillion)?/gi, '');
-                                } else if (aumValue.toLowerCase().includes('b')) {
-                                    multiplier = 1000000000; // billion
-                                    aumValue = aumValue.replace(/b(?:illion)?/gi, '');
-                                } else if (aumValue.toLowerCase().includes('m')) {
-                                    multiplier = 1000000; // million
-                                    aumValue = aumValue.replace(/m(?:illion)?/gi, '');
-                                } else if (aumValue.toLowerCase().includes('k')) {
-                                    multiplier = 1000; // thousand
-                                    aumValue = aumValue.replace(/k/gi, '');
-                                }
-                                
-                                // Convert European format (comma as decimal) to US format (period as decimal)
-                                // First check if we have both comma and period
-                                if (aumValue.includes(',') && aumValue.includes('.')) {
-                                    // Determine which is the decimal separator
-                                    const lastComma = aumValue.lastIndexOf(',');
-                                    const lastPeriod = aumValue.lastIndexOf('.');
-                                    if (lastComma > lastPeriod) {
-                                        // European format: 1.000.000,50
-                                        aumValue = aumValue.replace(/\./g, '').replace(',', '.');
-                                    } else {
-                                        // US format: 1,000,000.50
-                                        aumValue = aumValue.replace(/,/g, '');
-                                    }
-                                } else if (aumValue.includes(',')) {
-                                    // Check if comma is being used as decimal separator (European)
-                                    const parts = aumValue.split(',');
-                                    if (parts.length === 2 && parts[1].length <= 3) {
-                                        // Likely European decimal: 3,8 or 100,5 or 3,1
-                                        aumValue = aumValue.replace(',', '.');
-                                    } else {
-                                        // Likely thousands separator: 1,000
-                                        aumValue = aumValue.replace(/,/g, '');
-                                    }
-                                }
-                                
-                                // Parse the number
-                                const numValue = parseFloat(aumValue);
-                                if (!isNaN(numValue)) {
-                                    const finalValue = numValue * multiplier;
-                                    // Return as clean number in euros (rounded to avoid decimals)
-                                    return Math.round(finalValue).toString();
-                                }
+                ((labels) => {
+                    const absolute = (url) => {
+                        try {
+                            return new URL(url, window.location.href).href;
+                        } catch (e) {
+                            return '';
+                        }
+                    };
+
+                    // [url, rank] pairs, lower rank preferred
+                    const candidates = (el) => {
+                        const found = [];
+                        const link = el.tagName === 'A' ? el : el.closest('a');
+                        if (link && link.getAttribute('href')) {
+                            found.push([absolute(link.getAttribute('href')), 0]);
+                        }
+                        for (const node of [el, el.closest('[data-href]')]) {
+                            if (node && node.getAttribute('data-href')) {
+                                found.push([absolute(node.getAttribute('data-href')), 1]);
                             }
                         }
-                    }
-                    
-                    return '';
-                })()
+                        const card = el.closest('[href]');
+                        if (card && card !== link) {
+                            found.push([absolute(card.getAttribute('href')), 2]);
+                        }
+
+                        return found
+                            .filter(([url]) => /^https?:/.test(url) && !url.includes('undefined'))
+                            .map(([url, rank]) => [url, new URL(url).origin === window.location.origin ? rank : rank + 10]);
+                    };
+
+                    return Array.from(document.querySelectorAll('a, button'))
+                        .filter(el => el.innerText && labels.some(label => el.innerText.includes(label)))
+                        .map(el => {
+                            const ranked = candidates(el).sort((a, b) => a[1] - b[1]);
+                            return ranked.length ? ranked[0][0] : '';
+                        })
+                        .filter(url => url.length > 0);
+                })(__ARG__)
                 "#,
-            )
+                &self.details_labels,
+            )?)
             .await?
-            .into_value::<String>()?;
-        fund.aum = aum;
+            .into_value::<Vec<String>>()?;
+
+        Ok(fund_urls)
+    }
 
-        // Extract LinkedIn URL
-        let linkedin_url = page
+    /// Clicks the "Next" control, returning whether one was found.
+    async fn click_next_page(&self, page: &Page) -> Result<bool> {
+        let clicked = page
             .evaluate(
                 r#"
                 (() => {
-                    // Find LinkedIn links
-                    const links = Array.from(document.querySelectorAll('a[href*="linkedin.com"]'));
-                    for (const link of links) {
-                        const href = link.href || '';
-                        if (href.includes('linkedin.com/company/') || href.includes('linkedin.com/in/')) {
-                            return href;
+                    // First try to find and click "Next" button
+                    const nextButtons = Array.from(document.querySelectorAll('a, button'))
+                        .filter(el => {
+                            const text = (el.textContent || '').toLowerCase();
+                            const ariaLabel = (el.getAttribute('aria-label') || '').toLowerCase();
+                            return text === 'next' || 
+                                   text.includes('next') || 
+                                   ariaLabel.includes('next') ||
+                                   text === '→' ||
+                                   text === '>';
+                        });
+                    
+                    for (const btn of nextButtons) {
+                        // Check if next button is not disabled
+                        if (!btn.disabled && !btn.classList.contains('disabled')) {
+                            btn.click();
+                            return true;
                         }
                     }
                     
-                    // Check for LinkedIn in social media sections
-                    const socialLinks = Array.from(document.querySelectorAll('[class*="social"] a, [class*="Social"] a, footer a'));
-                    for (const link of socialLinks) {
-                        const href = link.href || '';
-                        if (href.includes('linkedin.com')) {
-                            return href;
+                    // Alternative: Look for numbered pagination
+                    const currentPage = document.querySelector('.pagination .active, [aria-current="page"]');
+                    if (currentPage) {
+                        const currentPageNum = parseInt(currentPage.textContent);
+                        // Find link with next page number
+                        const allLinks = Array.from(document.querySelectorAll('a'));
+                        const nextPageLink = allLinks.find(link => link.textContent.trim() === String(currentPageNum + 1));
+                        if (nextPageLink) {
+                            nextPageLink.click();
+                            return true;
                         }
                     }
                     
-                    // Check for LinkedIn icon links
-                    const iconLinks = Array.from(document.querySelectorAll('a[aria-label*="LinkedIn"], a[title*="LinkedIn"]'));
-                    for (const link of iconLinks) {
-                        const href = link.href || '';
-                        if (href) {
-                            return href;
+                    // Alternative: Look for page number links
+                    const pageLinks = Array.from(document.querySelectorAll('a'))
+                        .filter(el => {
+                            const text = el.textContent || '';
+                            return /^\d+$/.test(text.trim());
+                        })
+                        .sort((a, b) => parseInt(a.textContent) - parseInt(b.textContent));
+                    
+                    // Find current page and click next
+                    for (let i = 0; i < pageLinks.length - 1; i++) {
+                        if (pageLinks[i].classList.contains('active') || 
+                            pageLinks[i].getAttribute('aria-current') === 'page') {
+                            pageLinks[i + 1].click();
+                            return true;
                         }
                     }
                     
-                    return '';
+                    return false;
                 })()
                 "#,
             )
             .await?
-            .into_value::<String>()?;
-        fund.linkedin_url = linkedin_url;
+            .into_value::<bool>()?;
 
-        let description = page
+        Ok(clicked)
+    }
+
+    /// Text of the active page indicator, if the list has one.
+    async fn page_indicator(&self, page: &Page) -> Result<String> {
+        let indicator = page
             .evaluate(
                 r#"
                 (() => {
-                    // Define the boilerplate disclaimer text to exclude
-                    const boilerplateText = "The material presented via this website is for informational purposes only. Nothing in this website constitutes a solicitation for the purchase or sale of any financial product or service. Material presented on this website does not constitute a public offering of securities or investment management services in any jurisdiction. Investing in startup and early stage companies involves risks, including loss of capital, illiquidity, lack of dividends and dilution, and it should be done only as part of a diversified portfolio. The Investments presented in this website are suitable only for investors who are sufficiently sophisticated to understand these risks and make their own investment decisions.";
-                    
-                    const selectors = ['.description', '.about', '.overview', '[class*="description"]', '[class*="about"]'];
-                    for (const selector of selectors) {
-                        const el = document.querySelector(selector);
-                        if (el && el.textContent && el.textContent.length > 50) {
-                            let text = el.textContent.trim().replace(/\n+/g, ' ').replace(/\s+/g, ' ');
-                            // Remove boilerplate if present
-                            if (text.includes(boilerplateText)) {
-                                text = text.replace(boilerplateText, '').trim();
-                            }
-                            // Also check for partial boilerplate
-                            if (text.includes("The material presented via this website is for informational purposes only")) {
-                                const idx = text.indexOf("The material presented via this website");
-                                text = text.substring(0, idx).trim();
-                            }
-                            if (text.length > 20) {
-                                return text;
-                            }
-                        }
-                    }
-                    const paragraphs = Array.from(document.querySelectorAll('p'))
-                        .filter(p => {
-                            const text = p.textContent;
-                            return text && 
-                                   text.length > 100 && 
-                                   !text.includes("The material presented via this website");
-                        })
-                        .map(p => p.textContent.trim())
-                        .join(' ');
-                    if (paragraphs) {
-                        let cleanText = paragraphs.substring(0, 1000).replace(/\n+/g, ' ').replace(/\s+/g, ' ');
-                        // Final check to remove any remaining boilerplate
-                        if (cleanText.includes("The material presented via this website")) {
-                            const idx = cleanText.indexOf("The material presented via this website");
-                            cleanText = cleanText.substring(0, idx).trim();
-                        }
-                        return cleanText;
-                    }
-                    return '';
+                    const current = document.querySelector('.pagination .active, [aria-current="page"]');
+                    return current ? (current.textContent || '').trim() : '';
                 })()
                 "#,
             )
             .await?
             .into_value::<String>()?;
-        fund.fund_description = description;
 
-        let portfolio = page
-            .evaluate(
-                r#"
-                (() => {
-                    const portfolioCompanies = new Set();
-                    
-                    // First, look for text that contains "Portfolio" followed by company names
-                    const allElements = Array.from(document.querySelectorAll('*'));
-                    for (const el of allElements) {
-                        const text = el.textContent || '';
-                        
-                        // Check for pattern like "Portfolio: Company1, Company2" or "Portfolio Company1; Company2"
-                        if (text.includes('Portfolio') && !text.includes('portfolio management')) {
-                            // Extract text after "Portfolio" keyword
-                            const portfolioMatch = text.match(/Portfolio[:\s]+([^;]*(?:;[^;]*)*)/i);
-                            if (portfolioMatch && portfolioMatch[1]) {
-                                const companies = portfolioMatch[1]
-                                    .split(/[,;]/)
-                                    .map(c => c.trim())
-                                    .filter(c => {
-                                        // Filter out non-company text
-                                        return c.length > 2 && 
-                                               c.length < 100 && 
-                                               !c.toLowerCase().includes('cookies') &&
-                                               !c.toLowerCase().includes('material presented') &&
-                                               !c.toLowerCase().includes('website') &&
-                                               !c.toLowerCase().includes('aum') &&
-                                               (c.includes('Ventures') || 
-                                                c.includes('Capital') || 
-                                                c.includes('Partners') ||
-                                                c.includes('Fund') ||
-                                                c.includes('Labs') ||
-                                                c.includes('Accelerator'));
-                                    });
-                                companies.forEach(c => portfolioCompanies.add(c));
-                            }
-                        }
+        Ok(indicator)
+    }
+
+    /// Moves to the next list page and returns its URLs, or `None` on the last page.
+    ///
+    /// The "Next" element can be re-rendered between finding and clicking it, which
+    /// makes the click a silent no-op. So a click only counts once the first fund
+    /// URL or the page indicator has changed within `--page-wait-ms`, and is
+    /// retried once otherwise.
+    async fn advance_page(&self, page: &Page, current_urls: &[String]) -> Result<Option<Vec<String>>> {
+        let current_indicator = self.page_indicator(page).await?;
+
+        for attempt in 1..=2 {
+            if !self.click_next_page(page).await? {
+                return Ok(None);
+            }
+            // Polled rather than slept on, as the next page usually renders quickly
+            let started = Instant::now();
+            loop {
+                let next_urls = self.collect_page_urls(page).await?;
+                let next_indicator = self.page_indicator(page).await?;
+                let changed = next_urls.first() != current_urls.first() || next_indicator != current_indicator;
+                if changed && !next_urls.is_empty() {
+                    return Ok(Some(next_urls));
+                }
+                if started.elapsed() >= self.page_wait {
+                    break;
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+
+            warn!("Pagination click did not change the page (attempt {}/2)", attempt);
+        }
+
+        warn!("Page content unchanged after retrying the click, stopping pagination");
+        Ok(None)
+    }
+
+    /// Scrapes the profile at `url`. Failures are a `ScrapeError`, so
+    /// `scrape_with_retry` can tell the transient ones from the rest.
+    pub async fn scrape_fund_details(&self, url: &str) -> Result<Fund> {
+        info!("Scraping fund details from: {}", url);
+        if let Err(e) = reqwest::Url::parse(url) {
+            return Err(ScrapeError::NotFound(anyhow::anyhow!("Malformed URL {}: {}", url, e)).into());
+        }
+        let _open_page = self.open_pages.acquire().await?;
+
+        // Only loading hits the site; the permit is released before extracting
+        let navigation = self.navigations.acquire().await?;
+
+        // Covers opening the tab as well as the load, so a hung page can't
+        // block a worker for good
+        let started = Instant::now();
+        let deadline = tokio::time::Instant::now() + self.nav_timeout;
+        // Created in its own task so a tab that only appears after the
+        // deadline can still be closed instead of leaking
+        let browser = self.browser.clone();
+        let mut creating = tokio::spawn(async move { browser.read().await.new_page("about:blank").await });
+        let page = match tokio::time::timeout_at(deadline, &mut creating).await {
+            Ok(Ok(page)) => page.map_err(|e| ScrapeError::navigation(e.into()))?,
+            Ok(Err(e)) => return Err(ScrapeError::Navigation(e.into()).into()),
+            Err(_) => {
+                let late_url = url.to_string();
+                tokio::spawn(async move {
+                    if let Ok(Ok(page)) = creating.await {
+                        close_page(page, &late_url).await;
                     }
-                    
-                    // Also try to find portfolio sections with headers
-                    const portfolioSection = allElements.find(el => {
-                        const text = el.textContent || '';
-                        return text.toLowerCase().includes('portfolio') && 
-                               (el.tagName === 'H2' || el.tagName === 'H3' || el.tagName === 'H4');
-                    });
-                    
-                    if (portfolioSection) {
-                        let sibling = portfolioSection.nextElementSibling;
-                        let count = 0;
-                        while (sibling && count < 5) {  // Limit to next 5 siblings
-                            const items = sibling.querySelectorAll('li, a, span');
-                            items.forEach(item => {
-                                const text = item.textContent ? item.textContent.trim() : '';
-                                if (text && text.length > 2 && text.length < 100 &&
-                                    (text.includes('Ventures') || 
-                                     text.includes('Capital') || 
-                                     text.includes('Partners') ||
-                                     text.includes('Fund') ||
-                                     text.includes('Labs'))) {
-                                    portfolioCompanies.add(text);
-                                }
-                            });
-                            sibling = sibling.nextElementSibling;
-                            count++;
+                });
+                return Err(nav_timed_out(url, started).into());
+            }
+        };
+        let loading = async {
+            self.configure_tab(&page).await?;
+            self.load_profile(&page, url).await
+        };
+        match tokio::time::timeout_at(deadline, loading).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                close_page(page, url).await;
+                return Err(ScrapeError::navigation(e).into());
+            }
+            Err(_) => {
+                close_page(page, url).await;
+                return Err(nav_timed_out(url, started).into());
+            }
+        }
+        drop(navigation);
+
+        let fund = self.read_profile(&page, url).await;
+        // Left open, tabs pile up over a run until Chromium runs out of memory
+        close_page(page, url).await;
+        fund.map_err(|e| ScrapeError::extraction(e).into())
+    }
+
+    /// Extracts every field from the profile loaded in `page`.
+    async fn read_profile(&self, page: &Page, url: &str) -> Result<Fund> {
+        let mut builder = FundBuilder::new().url(url);
+
+        // Structured data is authored by the site, so it wins over rendered text
+        let structured = extract_structured_data(page).await?;
+        if !structured.is_empty() {
+            debug!("Found structured data on {}: {:?}", url, structured);
+        }
+        let profile = Profile::read(page).await?;
+
+        for extractor in &self.extractors {
+            let field = extractor.field();
+            let from_structured = structured
+                .get(field)
+                .map(|(value, strategy)| (extractor.clean_structured(value.to_string()), strategy))
+                .filter(|(value, _)| !value.is_empty());
+            let mut extracted = match from_structured {
+                Some((value, strategy)) => Extracted::new(value, strategy),
+                None => {
+                    if let Some(selector) = extractor.wait_for() {
+                        if !wait_for_selector(page, selector, SECTION_WAIT_TIMEOUT).await? {
+                            debug!("{} section did not render on {}, extracting anyway", field, url);
                         }
                     }
-                    
-                    // Filter out any remaining noise
-                    const cleanPortfolio = Array.from(portfolioCompanies)
-                        .filter(company => {
-                            const lower = company.toLowerCase();
-                            return !lower.includes('investing in startup') &&
-                                   !lower.includes('material presented') &&
-                                   !lower.includes('cookies') &&
-                                   !lower.includes('website');
-                        });
-                    
-                    return cleanPortfolio.join('; ');
-                })()
-                "#,
-            )
-            .await?
-            .into_value::<String>()?;
-        fund.fund_portfolio = portfolio;
+                    extractor.extract(&profile).await?
+                }
+            };
+            let raw = extracted.raw.take().unwrap_or_else(|| extracted.value.clone());
+            builder = builder
+                .confidence(field, extracted.confidence())
+                .raw(field, raw)
+                .provenance(field, &extracted.strategy)
+                .field(field, extracted.value)?;
+            for (related, value) in extracted.related {
+                builder = builder.provenance(&related, &extracted.strategy).field(&related, value)?;
+            }
+        }
+
+        let has_name = builder.get("fund_name").is_some_and(|name| !name.trim().is_empty());
+        builder = if has_name {
+            builder.name_source("page")
+        } else if let Some(name) = name_from_slug(url) {
+            warn!("No name found on {}, using slug-derived name '{}'", url, name);
+            builder
+                .name(name)
+                .name_source("slug")
+                .provenance("fund_name", "slug")
+                .confidence("fund_name", strategy_confidence("slug"))
+        } else {
+            builder.name_source("none")
+        };
+
+        // Without a stated count, the companies listed are a lower bound
+        let has_deal_count = builder.get("deal_count").is_some_and(|count| !count.is_empty());
+        let listed = builder
+            .get("fund_portfolio")
+            .map_or(0, |portfolio| portfolio.split(';').filter(|company| !company.trim().is_empty()).count());
+        if !has_deal_count && listed > 0 {
+            builder = builder
+                .field("deal_count", listed.to_string())?
+                .provenance("deal_count", "portfolio_count")
+                .confidence("deal_count", strategy_confidence("portfolio_count"));
+        }
+
+        let fund = builder.scraped_at(Utc::now()).build()?;
+
+        let empty_fields = empty_fields(&fund);
+        if !empty_fields.is_empty() {
+            debug!("Empty fields for {}: {}", url, empty_fields.join(", "));
+        }
 
         Ok(fund)
     }
 
-    pub async fn close(mut self) -> Result<()> {
-        self.browser.close().await?;
+    pub async fn close(self) -> Result<()> {
+        self.browser.write().await.close().await?;
         Ok(())
     }
 }
 
+async fn launch_browser(
+    headless: bool,
+    chrome_args: &[String],
+    chrome_path: Option<&Path>,
+    healthy: Arc<AtomicBool>,
+    generation: Arc<AtomicU64>,
+) -> Result<Browser> {
+    let launched_generation = generation.load(Ordering::SeqCst);
+
+    let mut config = BrowserConfig::builder();
+    if !headless {
+        config = config.with_head();
+    }
+    config = config.window_size(1920, 1080);
+    config = config.viewport(None);
+    config = config.args(chrome_args);
+    if let Some(chrome_path) = chrome_path {
+        config = config.chrome_executable(chrome_path);
+    }
+    
+    let browser_config = config.build()
+        .map_err(|e| anyhow::anyhow!("Failed to build browser config: {}", e))?;
+    
+    let (browser, mut handler) = Browser::launch(browser_config)
+        .await
+        .context("Failed to launch browser")?;
+
+    tokio::spawn(async move {
+        while let Some(h) = handler.next().await {
+            if let Err(e) = h {
+                error!("Browser handler error: {:?}", e);
+            }
+        }
+        // The handler only finishes once the connection to Chromium is gone
+        if generation.load(Ordering::SeqCst) == launched_generation {
+            warn!("Browser handler stopped");
+            healthy.store(false, Ordering::SeqCst);
+        }
+    });
+
+    Ok(browser)
+}
+
+/// Checks that `--chrome-path` names an executable file, so a typo fails
+/// up front instead of as an opaque launch error.
+fn validate_chrome_path(path: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(path);
+    let metadata = std::fs::metadata(&path)
+        .with_context(|| format!("Chromium binary {} not found", path.display()))?;
+    if !metadata.is_file() {
+        bail!("Chromium binary {} is not a file", path.display());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            bail!("Chromium binary {} is not executable", path.display());
+        }
+    }
+
+    Ok(path)
+}
+
+/// Periodically pings the browser and marks it unhealthy when it stops
+/// answering. The task ends once the scraper (and its browser) is dropped.
+fn spawn_health_check(browser: Weak<RwLock<Browser>>, healthy: Arc<AtomicBool>, interval: Duration) {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+
+            let Some(browser) = browser.upgrade() else {
+                break;
+            };
+            // Skip while a relaunch holds the write lock
+            let Ok(browser) = browser.try_read() else {
+                continue;
+            };
+
+            match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, browser.version()).await {
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => {
+                    error!("Browser health check failed: {}", e);
+                    healthy.store(false, Ordering::SeqCst);
+                }
+                Err(_) => {
+                    error!("Browser health check timed out after {:?}", HEALTH_CHECK_TIMEOUT);
+                    healthy.store(false, Ordering::SeqCst);
+                }
+            }
+        }
+    });
+}
+
+/// Derives a readable name from the last path segment of a profile URL,
+/// e.g. `https://www.vestbee.com/lp-list/acme-capital/` -> `Acme Capital`.
+pub fn name_from_slug(url: &str) -> Option<String> {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = without_scheme
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .split_once('/')?
+        .1;
+    let slug = path.split('/').rev().find(|segment| !segment.is_empty())?;
+
+    let name = slug
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Closes a tab we're done with. A failure only means the tab, or the whole
+/// browser, is already gone, so it is logged rather than returned.
+async fn close_page(page: Page, url: &str) {
+    if let Err(e) = page.close().await {
+        debug!("Failed to close the tab for {}: {}", url, e);
+    }
+}
+
+/// Polls until `selector` matches an element, for up to `timeout`. False if
+/// it never did.
+async fn wait_for_selector(page: &Page, selector: &str, timeout: Duration) -> Result<bool> {
+    let script = js_with_arg("document.querySelector(__ARG__) !== null", &selector)?;
+    let started = Instant::now();
+    loop {
+        if page.evaluate(script.as_str()).await?.into_value::<bool>()? {
+            return Ok(true);
+        }
+        if started.elapsed() >= timeout {
+            return Ok(false);
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// File discovery of `list_url` is checkpointed to. The default list keeps
+/// `DISCOVERY_CHECKPOINT`; other lists get their path added to the name so
+/// several lists discovered in one run resume independently.
+fn checkpoint_name(list_url: &str) -> String {
+    if list_url == LP_LIST_URL {
+        return DISCOVERY_CHECKPOINT.to_string();
+    }
+    let slug: String = canonical_url(list_url)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    DISCOVERY_CHECKPOINT.replace(".partial", &format!(".{}.partial", slug))
+}
+
+/// Names of the extracted fields that came back empty, in column order.
+/// Bookkeeping columns, and those only filled alongside another field, are
+/// left out.
+fn empty_fields(fund: &Fund) -> Vec<&'static str> {
+    FIELD_NAMES
+        .iter()
+        .filter(|column| {
+            !matches!(
+                **column,
+                "name_source" | "fund_url" | "source_list" | "aum_raw" | "aum_currency" | "fund_size_currency" | "scraped_at"
+            )
+        })
+        .filter(|column| fund.get_field(column).is_some_and(|value| value.is_empty()))
+        .copied()
+        .collect()
+}
+
 pub async fn scrape_with_retry(scraper: &VestbeeScraper, url: &str, max_retries: u32) -> Result<Fund> {
     let mut retries = 0;
     let mut delay = Duration::from_secs(2);
 
     loop {
-        match scraper.scrape_fund_details(url).await {
+        let e = match scraper.scrape_fund_details(url).await {
             Ok(fund) => return Ok(fund),
-            Err(e) if retries < max_retries => {
-                warn!("Attempt {} failed for {}: {:?}, retrying in {:?}", retries + 1, url, e, delay);
-                tokio::time::sleep(delay).await;
+            Err(e) => e,
+        };
+
+        match classify_failure(&e) {
+            RetryDecision::Retry(wait) if retries < max_retries => {
+                let wait = wait.unwrap_or(delay);
+                warn!("Attempt {} failed for {}: {:?}, retrying in {:?}", retries + 1, url, e, wait);
+                scraper.retries.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(wait).await;
                 delay *= 2;
                 retries += 1;
             }
-            Err(e) => {
+            RetryDecision::Retry(_) => {
                 error!("Failed to scrape {} after {} retries: {:?}", url, max_retries, e);
                 return Err(e);
             }
+            RetryDecision::GiveUp(reason) => {
+                error!("Not retrying {} ({}): {}", url, reason, e);
+                return Err(e);
+            }
+        }
+    }
+}
+
+/// Why a profile couldn't be scraped. Only `Navigation` and `Timeout` are
+/// worth retrying; a missing page or one we can't read stays that way.
+#[derive(Debug)]
+pub enum ScrapeError {
+    /// Opening the page failed: a dropped connection, a crashed tab, or an
+    /// error status from the server.
+    Navigation(anyhow::Error),
+    /// The browser didn't answer in time.
+    Timeout(anyhow::Error),
+    /// The page doesn't exist, or the URL can't address one.
+    NotFound(anyhow::Error),
+    /// The page loaded but its fields couldn't be extracted.
+    Parse(anyhow::Error),
+}
+
+impl ScrapeError {
+    /// Classifies a failure to open the profile.
+    fn navigation(e: anyhow::Error) -> Self {
+        if e.downcast_ref::<HttpStatusError>().is_some_and(|http| matches!(http.status, 404 | 410)) {
+            Self::NotFound(e)
+        } else if is_timeout(&e) {
+            Self::Timeout(e)
+        } else {
+            Self::Navigation(e)
+        }
+    }
+
+    /// Classifies a failure while reading the loaded profile. Losing the
+    /// browser is transient; anything else is down to the page itself.
+    fn extraction(e: anyhow::Error) -> Self {
+        if is_timeout(&e) {
+            return Self::Timeout(e);
+        }
+        match e.downcast_ref::<CdpError>() {
+            Some(CdpError::Ws(_) | CdpError::Io(_) | CdpError::NoResponse | CdpError::ChannelSendError(_)) => {
+                Self::Navigation(e)
+            }
+            _ => Self::Parse(e),
+        }
+    }
+
+    fn inner(&self) -> &anyhow::Error {
+        match self {
+            Self::Navigation(e) | Self::Timeout(e) | Self::NotFound(e) | Self::Parse(e) => e,
+        }
+    }
+}
+
+impl fmt::Display for ScrapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::Navigation(_) => "navigation failed",
+            Self::Timeout(_) => "timed out",
+            Self::NotFound(_) => "not found",
+            Self::Parse(_) => "could not parse page",
+        };
+        write!(f, "{}: {}", kind, self.inner())
+    }
+}
+
+impl std::error::Error for ScrapeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.inner().source()
+    }
+}
+
+fn is_timeout(e: &anyhow::Error) -> bool {
+    matches!(e.downcast_ref::<CdpError>(), Some(CdpError::Timeout))
+        || e.downcast_ref::<tokio::time::error::Elapsed>().is_some()
+}
+
+/// Logs and builds the error for a profile that didn't load within
+/// `--nav-timeout-secs`.
+fn nav_timed_out(url: &str, started: Instant) -> ScrapeError {
+    let elapsed = started.elapsed().as_secs_f64();
+    warn!("Loading {} timed out after {:.1}s (--nav-timeout-secs)", url, elapsed);
+    ScrapeError::Timeout(anyhow::anyhow!("Loading {} timed out after {:.1}s", url, elapsed))
+}
+
+/// Fails with an `HttpStatusError` if the document response for `url` has
+/// an error status.
+fn check_status(event: &EventResponseReceived, url: &str) -> Result<()> {
+    let status = u16::try_from(event.response.status).unwrap_or_default();
+    if status < 400 {
+        return Ok(());
+    }
+    let retry_after = event
+        .response
+        .headers
+        .inner()
+        .as_object()
+        .and_then(|headers| {
+            headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        })
+        .and_then(|(_, value)| value.as_str()?.trim().parse().ok())
+        .map(Duration::from_secs);
+    Err(HttpStatusError {
+        status,
+        url: url.to_string(),
+        retry_after,
+    }
+    .into())
+}
+
+/// The server's error status for a profile navigation.
+#[derive(Debug)]
+pub struct HttpStatusError {
+    pub status: u16,
+    pub url: String,
+    /// From a `Retry-After` header in seconds; HTTP dates are ignored.
+    pub retry_after: Option<Duration>,
+}
+
+impl fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HTTP {} for {}", self.status, self.url)
+    }
+}
+
+impl std::error::Error for HttpStatusError {}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RetryDecision {
+    /// Worth another attempt, after this wait instead of the backoff if set.
+    Retry(Option<Duration>),
+    /// Retrying can't help, for the reason given.
+    GiveUp(&'static str),
+}
+
+/// Decides whether a failed scrape is worth retrying. Only navigation
+/// failures and timeouts are; among error statuses, rate limiting (honoring
+/// `Retry-After`) and server errors are retried, while 401 means the profile
+/// needs a login and other client errors won't go away either.
+fn classify_failure(e: &anyhow::Error) -> RetryDecision {
+    let source = match e.downcast_ref::<ScrapeError>() {
+        Some(ScrapeError::Navigation(source)) => source,
+        Some(ScrapeError::Timeout(_)) | None => return RetryDecision::Retry(None),
+        Some(ScrapeError::NotFound(_)) => return RetryDecision::GiveUp("page not found"),
+        Some(ScrapeError::Parse(_)) => return RetryDecision::GiveUp("unparseable page"),
+    };
+    let Some(http) = source.downcast_ref::<HttpStatusError>() else {
+        return RetryDecision::Retry(None);
+    };
+    match http.status {
+        401 => RetryDecision::GiveUp("login required"),
+        429 => RetryDecision::Retry(http.retry_after),
+        500..=599 => RetryDecision::Retry(None),
+        _ => RetryDecision::GiveUp("persistent client error"),
+    }
+}
+
+/// Runs `scrape_with_retry`, converting a panic inside the scrape into a
+/// per-fund error so one bad page doesn't abort the whole run.
+pub async fn scrape_isolated(scraper: &VestbeeScraper, url: &str, max_retries: u32) -> Result<Fund> {
+    match AssertUnwindSafe(scrape_with_retry(scraper, url, max_retries))
+        .catch_unwind()
+        .await
+    {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            error!("Scrape panicked for {}: {}", url, message);
+            Err(anyhow::anyhow!("Scrape panicked: {}", message))
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_fields_cover_every_extracted_column() {
+        let mut fund = Fund::new();
+        fund.fund_url = "https://www.vestbee.com/lp/acme-capital".to_string();
+        fund.fund_name = "Acme Capital".to_string();
+        fund.deal_count = Some(12);
+        let empty = empty_fields(&fund);
+        assert!(!empty.contains(&"fund_name") && !empty.contains(&"deal_count"));
+        assert!(!empty.contains(&"fund_url") && !empty.contains(&"scraped_at"));
+        for column in ["aum", "ticket_size", "contact_phone", "investment_stages", "close_status", "management_fee", "carry"] {
+            assert!(empty.contains(&column), "{column}");
+        }
+    }
+
+    #[test]
+    fn names_funds_from_hyphenated_slugs() {
+        assert_eq!(
+            name_from_slug("https://www.vestbee.com/lp/acme-capital").as_deref(),
+            Some("Acme Capital")
+        );
+        assert_eq!(
+            name_from_slug("https://www.vestbee.com/lp/north_sea-ventures?ref=list").as_deref(),
+            Some("North Sea Ventures")
+        );
+    }
+
+    #[test]
+    fn ignores_trailing_slashes_in_slugs() {
+        assert_eq!(
+            name_from_slug("https://www.vestbee.com/lp/acme-capital/").as_deref(),
+            Some("Acme Capital")
+        );
+        assert_eq!(name_from_slug("https://www.vestbee.com/"), None);
+        assert_eq!(name_from_slug("https://www.vestbee.com"), None);
+    }
+
+    /// A profile navigation that failed with `status`, as `load_profile` reports it.
+    fn failed_with_status(status: u16, retry_after: Option<Duration>) -> anyhow::Error {
+        let http = HttpStatusError {
+            status,
+            url: "https://www.vestbee.com/lp/acme-capital".to_string(),
+            retry_after,
+        };
+        ScrapeError::navigation(http.into()).into()
+    }
+
+    #[test]
+    fn retries_server_errors_and_rate_limiting() {
+        assert_eq!(classify_failure(&failed_with_status(503, None)), RetryDecision::Retry(None));
+        assert_eq!(classify_failure(&failed_with_status(500, None)), RetryDecision::Retry(None));
+        assert_eq!(
+            classify_failure(&failed_with_status(429, Some(Duration::from_secs(30)))),
+            RetryDecision::Retry(Some(Duration::from_secs(30)))
+        );
+    }
+
+    #[test]
+    fn gives_up_on_persistent_client_errors() {
+        assert_eq!(classify_failure(&failed_with_status(404, None)), RetryDecision::GiveUp("page not found"));
+        assert_eq!(
+            classify_failure(&failed_with_status(403, None)),
+            RetryDecision::GiveUp("persistent client error")
+        );
+        assert_eq!(classify_failure(&failed_with_status(401, None)), RetryDecision::GiveUp("login required"));
+    }
+
+    #[test]
+    fn retries_timeouts_and_unclassified_failures() {
+        let timeout: anyhow::Error = ScrapeError::Timeout(anyhow::anyhow!("navigation timed out")).into();
+        assert_eq!(classify_failure(&timeout), RetryDecision::Retry(None));
+        assert_eq!(classify_failure(&anyhow::anyhow!("tab crashed")), RetryDecision::Retry(None));
+    }
+}
@@ -0,0 +1,115 @@
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use regex::Regex;
+use std::collections::{HashSet, VecDeque};
+use std::io::Read;
+use tracing::{info, warn};
+
+use crate::models::DiscoveredUrl;
+
+/// Guards against sitemap indexes that reference each other in a loop.
+const MAX_SITEMAPS: usize = 100;
+
+/// Collects profile URLs from a sitemap, following nested sitemap indexes.
+pub async fn fetch_fund_urls(sitemap_url: &str, profile_url_pattern: &str) -> Result<Vec<DiscoveredUrl>> {
+    let pattern = Regex::new(profile_url_pattern)
+        .with_context(|| format!("Invalid profile URL pattern: {}", profile_url_pattern))?;
+    let client = reqwest::Client::new();
+
+    let mut queue = VecDeque::from([sitemap_url.to_string()]);
+    let mut visited = HashSet::new();
+    let mut seen_urls = HashSet::new();
+    let mut fund_urls = Vec::new();
+
+    while let Some(url) = queue.pop_front() {
+        if !visited.insert(url.clone()) {
+            continue;
+        }
+        if visited.len() > MAX_SITEMAPS {
+            warn!("Reached maximum of {} sitemaps, stopping", MAX_SITEMAPS);
+            break;
+        }
+
+        info!("Fetching sitemap: {}", url);
+        let body = fetch_sitemap(&client, &url).await?;
+        let sitemap = parse_sitemap(&body).with_context(|| format!("Failed to parse sitemap {}", url))?;
+
+        if sitemap.is_index {
+            info!("Sitemap {} lists {} nested sitemaps", url, sitemap.locs.len());
+            queue.extend(sitemap.locs);
+            continue;
+        }
+
+        let before = fund_urls.len();
+        for loc in sitemap.locs {
+            if pattern.is_match(&loc) && seen_urls.insert(loc.clone()) {
+                fund_urls.push(loc);
+            }
+        }
+        info!("Found {} profile URLs in {}", fund_urls.len() - before, url);
+    }
+
+    Ok(DiscoveredUrl::index_all(fund_urls, sitemap_url))
+}
+
+async fn fetch_sitemap(client: &reqwest::Client, url: &str) -> Result<String> {
+    let bytes = client
+        .get(url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to fetch sitemap {}", url))?
+        .error_for_status()?
+        .bytes()
+        .await?;
+
+    // Gzipped sitemaps (`sitemap.xml.gz`) are usually served as raw gzip
+    // rather than with a Content-Encoding header, so sniff the magic bytes.
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        let mut xml = String::new();
+        GzDecoder::new(&bytes[..])
+            .read_to_string(&mut xml)
+            .with_context(|| format!("Failed to decompress sitemap {}", url))?;
+        Ok(xml)
+    } else {
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+struct Sitemap {
+    is_index: bool,
+    locs: Vec<String>,
+}
+
+fn parse_sitemap(xml: &str) -> Result<Sitemap> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut sitemap = Sitemap {
+        is_index: false,
+        locs: Vec::new(),
+    };
+    let mut in_loc = false;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"sitemapindex" => sitemap.is_index = true,
+                b"loc" => in_loc = true,
+                _ => {}
+            },
+            Event::End(e) if e.local_name().as_ref() == b"loc" => in_loc = false,
+            Event::Text(text) if in_loc => {
+                sitemap.locs.push(text.unescape()?.trim().to_string());
+            }
+            Event::CData(data) if in_loc => {
+                sitemap.locs.push(String::from_utf8_lossy(&data).trim().to_string());
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(sitemap)
+}
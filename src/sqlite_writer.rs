@@ -0,0 +1,107 @@
+use anyhow::{Context, Result};
+use rusqlite::{params_from_iter, Connection};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::models::{canonical_url, Fund, FIELD_NAMES};
+
+/// Keeps funds in a SQLite database, one row per fund in a `funds` table
+/// with a text column per field. Rows are matched on a `canonical_url`
+/// column (see `models::canonical_url`) and funds already in the table are
+/// updated in place, so repeated runs against the same database refresh it
+/// rather than piling up duplicates, however a profile's URL was written.
+pub struct SqliteExporter {
+    connection: Connection,
+    path: PathBuf,
+    upsert: String,
+}
+
+impl SqliteExporter {
+    /// Opens the database, creating it and the `funds` table if missing.
+    /// Columns for fields added since the table was created are added too.
+    pub fn new(path: &str) -> Result<Self> {
+        let connection = Connection::open(path).with_context(|| format!("Failed to open {}", path))?;
+
+        let columns: Vec<String> = FIELD_NAMES
+            .iter()
+            .map(|field| match *field {
+                "fund_url" => "fund_url TEXT PRIMARY KEY".to_string(),
+                field => format!("{} TEXT", field),
+            })
+            .collect();
+        connection.execute(&format!("CREATE TABLE IF NOT EXISTS funds ({})", columns.join(", ")), [])?;
+
+        let existing: HashSet<String> = connection
+            .prepare("SELECT name FROM pragma_table_info('funds')")?
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        for field in FIELD_NAMES.iter().filter(|field| !existing.contains(**field)) {
+            connection.execute(&format!("ALTER TABLE funds ADD COLUMN {} TEXT", field), [])?;
+        }
+        if !existing.contains("canonical_url") {
+            connection.execute("ALTER TABLE funds ADD COLUMN canonical_url TEXT", [])?;
+            backfill_canonical_urls(&connection)?;
+        }
+        connection.execute(
+            "CREATE UNIQUE INDEX IF NOT EXISTS funds_canonical_url ON funds (canonical_url)",
+            [],
+        )?;
+
+        let placeholders: Vec<String> = (1..=FIELD_NAMES.len() + 1).map(|n| format!("?{}", n)).collect();
+        let updates: Vec<String> = FIELD_NAMES
+            .iter()
+            .map(|field| format!("{0} = excluded.{0}", field))
+            .collect();
+        let upsert = format!(
+            "INSERT INTO funds ({}, canonical_url) VALUES ({}) ON CONFLICT(canonical_url) DO UPDATE SET {}",
+            FIELD_NAMES.join(", "),
+            placeholders.join(", "),
+            updates.join(", ")
+        );
+
+        Ok(Self {
+            connection,
+            path: PathBuf::from(path),
+            upsert,
+        })
+    }
+
+    pub fn write_fund(&mut self, fund: &Fund) -> Result<()> {
+        let values = FIELD_NAMES
+            .iter()
+            .map(|field| fund.get_field(field).unwrap_or_default().into_owned())
+            .chain([canonical_url(&fund.fund_url)]);
+        self.connection
+            .prepare_cached(&self.upsert)?
+            .execute(params_from_iter(values))
+            .with_context(|| format!("Failed to write {} to {}", fund.fund_url, self.path.display()))?;
+        Ok(())
+    }
+
+    /// Closes the database and returns its path.
+    pub fn finalize(self) -> Result<Vec<PathBuf>> {
+        self.connection.close().map_err(|(_, e)| e)?;
+        Ok(vec![self.path])
+    }
+}
+
+/// Fills `canonical_url` in for the rows of a table created before it
+/// existed. Of rows whose URLs turn out to be the same page, only the first
+/// is kept, so the column can be made unique.
+fn backfill_canonical_urls(connection: &Connection) -> Result<()> {
+    let urls: Vec<String> = connection
+        .prepare("SELECT fund_url FROM funds ORDER BY rowid")?
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut seen = HashSet::new();
+    for url in urls {
+        let canonical = canonical_url(&url);
+        if seen.insert(canonical.clone()) {
+            connection.execute("UPDATE funds SET canonical_url = ?1 WHERE fund_url = ?2", [&canonical, &url])?;
+        } else {
+            connection.execute("DELETE FROM funds WHERE fund_url = ?1", [&url])?;
+        }
+    }
+    Ok(())
+}
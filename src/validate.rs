@@ -0,0 +1,47 @@
+use anyhow::{bail, Result};
+use tracing::{error, info, warn};
+
+use crate::csv_writer::read_funds;
+
+/// `validate-file <path>`: checks a delivered CSV before it is loaded
+/// anywhere. Every problem is logged with its line number; the command
+/// fails if any row has a hard error, so it can gate an ingestion job.
+pub fn run(path: &str) -> Result<()> {
+    let csv = read_funds(path)?;
+
+    let mut errors = 0;
+    let mut warnings = 0;
+    for (line, fund) in &csv.rows {
+        let fund = match fund {
+            Ok(fund) => fund,
+            Err(e) => {
+                error!("Line {}: {}", line, e);
+                errors += 1;
+                continue;
+            }
+        };
+
+        for problem in fund.validate() {
+            if problem.is_error {
+                error!("Line {}: {}: {}", line, problem.field, problem.message);
+                errors += 1;
+            } else {
+                warn!("Line {}: {}: {}", line, problem.field, problem.message);
+                warnings += 1;
+            }
+        }
+    }
+
+    info!(
+        "Checked {} rows in {} ({} columns): {} errors, {} warnings",
+        csv.rows.len(),
+        path,
+        csv.columns.len(),
+        errors,
+        warnings
+    );
+    if errors > 0 {
+        bail!("{} failed validation with {} errors", path, errors);
+    }
+    Ok(())
+}